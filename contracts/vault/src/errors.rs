@@ -22,6 +22,12 @@ pub enum VaultError {
     NotASigner = 201,
     /// Caller lacks required role
     InsufficientRole = 202,
+    /// Permission mask contains unknown bits
+    InvalidPermissions = 203,
+    /// Change would leave the vault without an Admin
+    LastAdmin = 204,
+    /// Role expiry is not in the future, or is set on an Admin grant
+    InvalidRoleExpiry = 205,
 
     // Proposal errors (3xx)
     /// Proposal does not exist
@@ -36,6 +42,10 @@ pub enum VaultError {
     ProposalNotApproved = 304,
     /// Proposal has already been executed
     ProposalAlreadyExecuted = 305,
+    /// Proposal is not approved and still within its timelock
+    ProposalNotTimelocked = 306,
+    /// Signer has already vetoed this proposal
+    AlreadyVetoed = 307,
 
     // Spending limit errors (4xx)
     /// Amount exceeds per-proposal spending limit
@@ -48,8 +58,18 @@ pub enum VaultError {
     InvalidAmount = 403,
     /// Proposal is timelocked and cannot be executed yet
     TimelockNotExpired = 404,
-    /// Recurring payment interval too short
+    /// Interval, delay or period is shorter than the allowed minimum
     IntervalTooShort = 405,
+    /// Amount would exceed the category's monthly budget
+    ExceedsMonthlyBudget = 406,
+    /// Amount would exceed the category's quarterly budget
+    ExceedsQuarterlyBudget = 407,
+    /// Proposal category has no configured budget
+    UnknownBudgetCategory = 408,
+    /// Amount would exceed the rolling cap for the recipient
+    ExceedsRecipientCap = 409,
+    /// Amount would exceed the rolling cap for the proposer
+    ExceedsProposerCap = 410,
 
     // Configuration errors (5xx)
     /// Threshold must be at least 1
@@ -64,10 +84,46 @@ pub enum VaultError {
     CannotRemoveSigner = 504,
     /// At least one signer is required
     NoSigners = 505,
+    /// Limits must satisfy spending_limit <= daily_limit <= weekly_limit
+    LimitsOutOfOrder = 506,
+    /// Timelock tiers must have positive, strictly increasing amounts and non-decreasing delays
+    InvalidTimelockTiers = 507,
+    /// Signer set would exceed the configured maximum
+    TooManySigners = 508,
 
     // Token errors (6xx)
     /// Token transfer failed
     TransferFailed = 600,
     /// Insufficient vault balance
     InsufficientBalance = 601,
+    /// Token is not in the supported token registry, or is disabled there
+    TokenNotSupported = 602,
+    /// Token is already in the registry
+    TokenAlreadyRegistered = 604,
+
+    // Vesting errors (7xx)
+    /// Vesting grant does not exist
+    VestingNotFound = 700,
+    /// Vesting grant is not active
+    VestingNotActive = 701,
+    /// Cliff must not exceed duration, and duration must be positive
+    InvalidVestingSchedule = 703,
+    /// No vested tokens are available to claim
+    NothingToClaim = 704,
+
+    // Streaming errors (8xx)
+    /// Stream does not exist
+    StreamNotFound = 800,
+    /// Stream is not active
+    StreamNotActive = 801,
+
+    // Address book errors (9xx)
+    /// Recipient is not in the address book while allowlist-only mode is on
+    RecipientNotAllowed = 900,
+    /// Address book entry does not exist
+    AddressNotInBook = 901,
+    /// Address book proposal does not exist
+    AddressBookProposalNotFound = 903,
+    /// Recipient is on the denylist
+    RecipientDenylisted = 904,
 }
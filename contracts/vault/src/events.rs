@@ -4,6 +4,8 @@
 
 use soroban_sdk::{Address, Env, Symbol};
 
+use crate::types::Config;
+
 /// Emit when contract is initialized
 pub fn emit_initialized(env: &Env, admin: &Address, threshold: u32) {
     env.events().publish(
@@ -107,16 +109,101 @@ pub fn emit_proposal_rejected(env: &Env, proposal_id: u64, rejector: &Address) {
     );
 }
 
+/// Emit when a signer vetoes a timelocked proposal
+pub fn emit_proposal_vetoed(env: &Env, proposal_id: u64, signer: &Address, reason: &Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_vetoed"), proposal_id),
+        (signer.clone(), reason.clone()),
+    );
+}
+
+/// Emit when vetoes cancel a timelocked proposal
+pub fn emit_proposal_cancelled(env: &Env, proposal_id: u64, reason: &Symbol) {
+    env.events().publish(
+        (Symbol::new(env, "proposal_cancelled"), proposal_id),
+        reason.clone(),
+    );
+}
+
 /// Emit when a role is assigned
 pub fn emit_role_assigned(env: &Env, addr: &Address, role: u32) {
     env.events()
         .publish((Symbol::new(env, "role_assigned"),), (addr.clone(), role));
 }
 
-/// Emit when config is updated
-pub fn emit_config_updated(env: &Env, updater: &Address) {
-    env.events()
-        .publish((Symbol::new(env, "config_updated"),), updater.clone());
+/// Emit when an Admin grant is proposed
+pub fn emit_admin_grant_proposed(env: &Env, grant_id: u64, proposer: &Address, target: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "admin_grant_proposed"), grant_id),
+        (proposer.clone(), target.clone()),
+    );
+}
+
+/// Emit when a signer approves an Admin grant
+pub fn emit_admin_grant_approved(env: &Env, grant_id: u64, signer: &Address, approvals: u32) {
+    env.events().publish(
+        (Symbol::new(env, "admin_grant_approved"), grant_id),
+        (signer.clone(), approvals),
+    );
+}
+
+/// Emit when an address's permission mask is overridden
+pub fn emit_permissions_set(env: &Env, addr: &Address, admin: &Address, permissions: u32) {
+    env.events().publish(
+        (Symbol::new(env, "permissions_set"),),
+        (addr.clone(), admin.clone(), permissions),
+    );
+}
+
+/// Emit when a time-limited role grant lapses to Member
+pub fn emit_role_expired(env: &Env, addr: &Address, role: u32, expired_at: u64) {
+    env.events().publish(
+        (Symbol::new(env, "role_expired"),),
+        (addr.clone(), role, expired_at),
+    );
+}
+
+/// Emit when a time-limited role grant is renewed
+pub fn emit_role_renewed(env: &Env, addr: &Address, admin: &Address, expires_at: Option<u64>) {
+    env.events().publish(
+        (Symbol::new(env, "role_renewed"),),
+        (addr.clone(), admin.clone(), expires_at),
+    );
+}
+
+/// Emit when config is updated, with the configuration before and after
+pub fn emit_config_updated(env: &Env, updater: &Address, old: &Config, new: &Config) {
+    env.events().publish(
+        (Symbol::new(env, "config_updated"),),
+        (updater.clone(), old.clone(), new.clone()),
+    );
+}
+
+/// Emit when a category budget is set
+pub fn emit_budget_set(
+    env: &Env,
+    category: &Symbol,
+    admin: &Address,
+    monthly_cap: i128,
+    quarterly_cap: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "budget_set"), category.clone()),
+        (admin.clone(), monthly_cap, quarterly_cap),
+    );
+}
+
+/// Emit when per-address caps are updated
+pub fn emit_address_caps_updated(
+    env: &Env,
+    admin: &Address,
+    recipient_cap: i128,
+    proposer_cap: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "address_caps_updated"),),
+        (admin.clone(), recipient_cap, proposer_cap),
+    );
 }
 
 /// Emit when a signer is added
@@ -127,6 +214,14 @@ pub fn emit_signer_added(env: &Env, signer: &Address, total_signers: u32) {
     );
 }
 
+/// Emit when a signer key is rotated
+pub fn emit_signer_rotated(env: &Env, old: &Address, new: &Address, proposals_updated: u32) {
+    env.events().publish(
+        (Symbol::new(env, "signer_rotated"),),
+        (old.clone(), new.clone(), proposals_updated),
+    );
+}
+
 /// Emit when a signer is removed
 pub fn emit_signer_removed(env: &Env, signer: &Address, total_signers: u32) {
     env.events().publish(
@@ -134,3 +229,309 @@ pub fn emit_signer_removed(env: &Env, signer: &Address, total_signers: u32) {
         (signer.clone(), total_signers),
     );
 }
+
+/// Emit when a vesting grant is proposed
+pub fn emit_vesting_created(
+    env: &Env,
+    vesting_id: u64,
+    proposer: &Address,
+    beneficiary: &Address,
+    total: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "vesting_created"), vesting_id),
+        (proposer.clone(), beneficiary.clone(), total),
+    );
+}
+
+/// Emit when a vesting grant is approved by a signer
+pub fn emit_vesting_approved(env: &Env, vesting_id: u64, approver: &Address, approval_count: u32) {
+    env.events().publish(
+        (Symbol::new(env, "vesting_approved"), vesting_id),
+        (approver.clone(), approval_count),
+    );
+}
+
+/// Emit when a vesting grant reaches threshold and starts releasing tokens
+pub fn emit_vesting_activated(env: &Env, vesting_id: u64) {
+    env.events()
+        .publish((Symbol::new(env, "vesting_activated"), vesting_id), ());
+}
+
+/// Emit when a beneficiary claims vested tokens
+pub fn emit_vesting_claimed(env: &Env, vesting_id: u64, beneficiary: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "vesting_claimed"), vesting_id),
+        (beneficiary.clone(), amount),
+    );
+}
+
+/// Emit when a vesting grant is revoked
+pub fn emit_vesting_revoked(env: &Env, vesting_id: u64, admin: &Address, unvested: i128) {
+    env.events().publish(
+        (Symbol::new(env, "vesting_revoked"), vesting_id),
+        (admin.clone(), unvested),
+    );
+}
+
+/// Emit when a payment stream is proposed
+pub fn emit_stream_created(
+    env: &Env,
+    stream_id: u64,
+    proposer: &Address,
+    recipient: &Address,
+    rate_per_ledger: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "stream_created"), stream_id),
+        (proposer.clone(), recipient.clone(), rate_per_ledger),
+    );
+}
+
+/// Emit when a payment stream is approved by a signer
+pub fn emit_stream_approved(env: &Env, stream_id: u64, approver: &Address, approval_count: u32) {
+    env.events().publish(
+        (Symbol::new(env, "stream_approved"), stream_id),
+        (approver.clone(), approval_count),
+    );
+}
+
+/// Emit when a payment stream reaches threshold and starts accruing
+pub fn emit_stream_started(env: &Env, stream_id: u64, start_ledger: u64, end_ledger: u64) {
+    env.events().publish(
+        (Symbol::new(env, "stream_started"), stream_id),
+        (start_ledger, end_ledger),
+    );
+}
+
+/// Emit when a recipient withdraws from a stream
+pub fn emit_stream_withdrawn(env: &Env, stream_id: u64, recipient: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "stream_withdrawn"), stream_id),
+        (recipient.clone(), amount),
+    );
+}
+
+/// Emit when a stream is cancelled
+pub fn emit_stream_cancelled(
+    env: &Env,
+    stream_id: u64,
+    canceller: &Address,
+    owed: i128,
+    refunded: i128,
+) {
+    env.events().publish(
+        (Symbol::new(env, "stream_cancelled"), stream_id),
+        (canceller.clone(), owed, refunded),
+    );
+}
+
+/// Emit when a due recurring payment could not be made and was added to arrears
+pub fn emit_recurring_failed(env: &Env, payment_id: u64, amount: i128, reason: u32, arrears: i128) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_failed"), payment_id),
+        (amount, reason, arrears),
+    );
+}
+
+/// Emit when outstanding recurring payment arrears are paid
+pub fn emit_arrears_settled(env: &Env, payment_id: u64, recipient: &Address, amount: i128) {
+    env.events().publish(
+        (Symbol::new(env, "arrears_settled"), payment_id),
+        (recipient.clone(), amount),
+    );
+}
+
+/// Emit when limit reservation is toggled for a recurring payment
+pub fn emit_recurring_reservation_updated(
+    env: &Env,
+    payment_id: u64,
+    admin: &Address,
+    enabled: bool,
+) {
+    env.events().publish(
+        (Symbol::new(env, "recurring_reserved"), payment_id),
+        (admin.clone(), enabled),
+    );
+}
+
+/// Emit when an address book change is proposed
+pub fn emit_address_book_proposed(
+    env: &Env,
+    proposal_id: u64,
+    proposer: &Address,
+    addr: &Address,
+    action: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "addr_book_proposed"), proposal_id),
+        (proposer.clone(), addr.clone(), action),
+    );
+}
+
+/// Emit when an address book change is approved by a signer
+pub fn emit_address_book_approved(
+    env: &Env,
+    proposal_id: u64,
+    approver: &Address,
+    approval_count: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "addr_book_approved"), proposal_id),
+        (approver.clone(), approval_count),
+    );
+}
+
+/// Emit when an address book change takes effect
+pub fn emit_address_book_updated(env: &Env, addr: &Address, label: &Symbol, action: u32) {
+    env.events().publish(
+        (Symbol::new(env, "addr_book_updated"),),
+        (addr.clone(), label.clone(), action),
+    );
+}
+
+/// Emit when an address is added to the denylist
+pub fn emit_address_denylisted(
+    env: &Env,
+    addr: &Address,
+    by: &Address,
+    halted_schedules: u32,
+    flagged_proposals: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "address_denylisted"),),
+        (
+            addr.clone(),
+            by.clone(),
+            halted_schedules,
+            flagged_proposals,
+        ),
+    );
+}
+
+/// Emit when an address is removed from the denylist
+pub fn emit_address_undenylisted(env: &Env, addr: &Address, by: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "address_undenylisted"),),
+        (addr.clone(), by.clone()),
+    );
+}
+
+/// Emit when a token is added to the registry
+pub fn emit_token_registered(env: &Env, token_addr: &Address, admin: &Address, decimals: u32) {
+    env.events().publish(
+        (Symbol::new(env, "token_registered"),),
+        (token_addr.clone(), admin.clone(), decimals),
+    );
+}
+
+/// Emit when a registered token is enabled or disabled
+pub fn emit_token_status_changed(env: &Env, token_addr: &Address, admin: &Address, enabled: bool) {
+    env.events().publish(
+        (Symbol::new(env, "token_status"),),
+        (token_addr.clone(), admin.clone(), enabled),
+    );
+}
+
+/// Emit when a token is removed from the registry
+pub fn emit_token_removed(env: &Env, token_addr: &Address, admin: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "token_removed"),),
+        (token_addr.clone(), admin.clone()),
+    );
+}
+
+/// Emit when registry enforcement is switched on or off
+pub fn emit_token_registry_enforced(env: &Env, admin: &Address, enforced: bool) {
+    env.events().publish(
+        (Symbol::new(env, "token_registry_enforced"),),
+        (admin.clone(), enforced),
+    );
+}
+
+/// Emit when timelock tiers are replaced
+pub fn emit_timelock_tiers_updated(env: &Env, admin: &Address, tier_count: u32) {
+    env.events().publish(
+        (Symbol::new(env, "timelock_tiers"),),
+        (admin.clone(), tier_count),
+    );
+}
+
+/// Emit when a recovery or succession settings change is proposed
+pub fn emit_safeguard_proposed(env: &Env, safeguard_id: u64, proposer: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "safeguard_proposed"), safeguard_id),
+        proposer.clone(),
+    );
+}
+
+/// Emit when a signer approves a safeguard change
+pub fn emit_safeguard_approved(env: &Env, safeguard_id: u64, signer: &Address, approvals: u32) {
+    env.events().publish(
+        (Symbol::new(env, "safeguard_approved"), safeguard_id),
+        (signer.clone(), approvals),
+    );
+}
+
+/// Emit when a recovery guardian proposes a new signer set
+pub fn emit_recovery_proposed(
+    env: &Env,
+    recovery_id: u64,
+    recovery_guardian: &Address,
+    signers: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "recovery_proposed"), recovery_id),
+        (recovery_guardian.clone(), signers),
+    );
+}
+
+/// Emit when a recovery guardian approves a recovery
+pub fn emit_recovery_approved(
+    env: &Env,
+    recovery_id: u64,
+    recovery_guardian: &Address,
+    approvals: u32,
+    executable_at: u64,
+) {
+    env.events().publish(
+        (Symbol::new(env, "recovery_approved"), recovery_id),
+        (recovery_guardian.clone(), approvals, executable_at),
+    );
+}
+
+/// Emit when an existing signer cancels a recovery
+pub fn emit_recovery_cancelled(env: &Env, recovery_id: u64, signer: &Address) {
+    env.events().publish(
+        (Symbol::new(env, "recovery_cancelled"), recovery_id),
+        signer.clone(),
+    );
+}
+
+/// Emit when a recovery installs the new signer set
+pub fn emit_recovery_executed(env: &Env, recovery_id: u64, signers: u32, threshold: u32) {
+    env.events().publish(
+        (Symbol::new(env, "recovery_executed"), recovery_id),
+        (signers, threshold),
+    );
+}
+
+/// Emit when succession is triggered after signer inactivity
+pub fn emit_succession_triggered(
+    env: &Env,
+    caller: &Address,
+    last_activity: u64,
+    successors: u32,
+    tokens_swept: u32,
+) {
+    env.events().publish(
+        (Symbol::new(env, "succession_triggered"),),
+        (caller.clone(), last_activity, successors, tokens_swept),
+    );
+}
+
+/// Emit when a signer sends a liveness heartbeat
+pub fn emit_heartbeat(env: &Env, signer: &Address, ledger: u64) {
+    env.events()
+        .publish((Symbol::new(env, "heartbeat"),), (signer.clone(), ledger));
+}
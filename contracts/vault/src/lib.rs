@@ -18,8 +18,13 @@ use errors::VaultError;
 use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
 #[allow(unused_imports)]
 use types::{
-    AmountTier, Config, Priority, Proposal, ProposalStatus, Role, ThresholdStrategy,
-    TimeBasedThreshold,
+    AddressBookAction, AddressBookEntry, AddressBookProposal, AddressCaps, AdminGrant, AmountTier,
+    BudgetCategory, BudgetPeriod, Config, ConfigUpdate, LimitWindowMode, Permission, Priority,
+    Proposal, ProposalStatus, RecoveryConfig, RecoveryProposal, RecoveryStatus,
+    RecurringExecResult, RecurringPayment, Role, RoleChange, SafeguardChange, SafeguardProposal,
+    SignerActivity, SpendWindow, Stream, StreamStatus, SuccessionPlan, ThresholdStrategy,
+    TimeBasedThreshold, TimelockClock, TimelockTier, TokenInfo, VestingGrant, VestingSchedule,
+    VestingStatus, ALL_PERMISSIONS,
 };
 
 /// The main contract structure for VaultDAO.
@@ -29,15 +34,60 @@ use types::{
 #[contract]
 pub struct VaultDAO;
 
+/// Reservation horizon for the daily limit: ~24 hours in ledgers
+const DAY_IN_LEDGERS: u64 = storage::DAY_IN_LEDGERS as u64;
+
 /// Proposal expiration: ~7 days in ledgers (5 seconds per ledger)
 const PROPOSAL_EXPIRY_LEDGERS: u64 = 120_960;
 
+/// Proposal expiration: 7 days in seconds
+const PROPOSAL_EXPIRY_SECONDS: u64 = 604_800;
+
+/// Minimum social recovery delay: ~3 days in ledgers
+const MIN_RECOVERY_DELAY: u64 = 3 * DAY_IN_LEDGERS;
+
+/// Minimum inactivity period before succession: ~30 days in ledgers
+const MIN_INACTIVITY_PERIOD: u64 = 30 * DAY_IN_LEDGERS;
+
+/// Nominal ledger close time, used to convert between ledgers and seconds
+const SECONDS_PER_LEDGER: u64 = 5;
+
+/// Convert a delay in the vault's clock unit to (ledgers, seconds).
+///
+/// The representation matching `timelock_clock` is exact; the other is an
+/// estimate based on the nominal ledger close time.
+fn delay_in_both_clocks(config: &Config, delay: u64) -> (u64, u64) {
+    match config.timelock_clock {
+        TimelockClock::Ledger => (delay, delay * SECONDS_PER_LEDGER),
+        TimelockClock::Timestamp => (delay.div_ceil(SECONDS_PER_LEDGER), delay),
+    }
+}
+
+/// Whether a proposal is past its expiry under the vault's clock.
+fn is_expired(env: &Env, config: &Config, proposal: &Proposal) -> bool {
+    match config.timelock_clock {
+        TimelockClock::Ledger => env.ledger().sequence() as u64 > proposal.expires_at,
+        TimelockClock::Timestamp => env.ledger().timestamp() > proposal.expires_time,
+    }
+}
+
+/// Whether a proposal is still within its timelock under the vault's clock.
+fn is_timelocked(env: &Env, config: &Config, proposal: &Proposal) -> bool {
+    if proposal.unlock_ledger == 0 {
+        return false;
+    }
+    match config.timelock_clock {
+        TimelockClock::Ledger => (env.ledger().sequence() as u64) < proposal.unlock_ledger,
+        TimelockClock::Timestamp => env.ledger().timestamp() < proposal.unlock_time,
+    }
+}
+
 /// Calculate required threshold based on strategy
 fn calculate_required_threshold(env: &Env, config: &Config, proposal: &Proposal) -> u32 {
     match &config.threshold_strategy {
         ThresholdStrategy::Fixed => config.threshold,
         ThresholdStrategy::Percentage(pct) => {
-            let signers_count = config.signers.len();
+            let signers_count = active_signer_count(env, config);
             let required = (signers_count * pct).div_ceil(100);
             required.max(1).min(signers_count)
         }
@@ -64,6 +114,740 @@ fn calculate_required_threshold(env: &Env, config: &Config, proposal: &Proposal)
     }
 }
 
+/// Validate the threshold strategy against the current signer set.
+///
+/// Every threshold the strategy can require must lie within 1..=signers.
+fn validate_threshold_strategy(env: &Env, config: &Config) -> Result<(), VaultError> {
+    let signers = config.signers.len();
+    let check = |threshold: u32| {
+        if threshold < 1 {
+            Err(VaultError::ThresholdTooLow)
+        } else if threshold > signers {
+            Err(VaultError::ThresholdTooHigh)
+        } else {
+            Ok(())
+        }
+    };
+    check(config.threshold)?;
+    check(storage::get_veto_threshold(env))?;
+    match &config.threshold_strategy {
+        ThresholdStrategy::Fixed => {}
+        ThresholdStrategy::Percentage(pct) => {
+            if *pct < 1 {
+                return Err(VaultError::ThresholdTooLow);
+            }
+            if *pct > 100 {
+                return Err(VaultError::ThresholdTooHigh);
+            }
+        }
+        ThresholdStrategy::AmountBased(tiers) => {
+            for tier in tiers.iter() {
+                check(tier.approvals)?;
+            }
+        }
+        ThresholdStrategy::TimeBased(time_config) => {
+            check(time_config.initial_threshold)?;
+            check(time_config.reduced_threshold)?;
+        }
+    }
+    Ok(())
+}
+
+/// Highest number of approvals or vetoes the vault can require.
+///
+/// `Percentage` scales with the signer set, so only the base threshold binds.
+fn max_required_approvals(env: &Env, config: &Config) -> u32 {
+    let mut required = config.threshold.max(storage::get_veto_threshold(env));
+    match &config.threshold_strategy {
+        ThresholdStrategy::Fixed | ThresholdStrategy::Percentage(_) => {}
+        ThresholdStrategy::AmountBased(tiers) => {
+            for tier in tiers.iter() {
+                required = required.max(tier.approvals);
+            }
+        }
+        ThresholdStrategy::TimeBased(time_config) => {
+            required = required
+                .max(time_config.initial_threshold)
+                .max(time_config.reduced_threshold);
+        }
+    }
+    required
+}
+
+/// Reject signer sets larger than the configured maximum.
+fn check_signer_count(env: &Env, count: u32) -> Result<(), VaultError> {
+    if count > storage::get_max_signers(env) {
+        return Err(VaultError::TooManySigners);
+    }
+    Ok(())
+}
+
+/// Whether a signer voted or sent a heartbeat within the last `window` ledgers.
+fn is_active_signer(env: &Env, signer: &Address, window: u64) -> bool {
+    let activity = storage::get_signer_activity(env, signer);
+    let last_seen = activity.last_vote.max(activity.last_heartbeat);
+    last_seen > 0 && (env.ledger().sequence() as u64).saturating_sub(last_seen) <= window
+}
+
+/// Signer count used by the `Percentage` strategy.
+///
+/// With an active-signer window set, only signers active within it are
+/// counted, but never fewer than `config.threshold`; if none are, the full
+/// signer set is used.
+fn active_signer_count(env: &Env, config: &Config) -> u32 {
+    let window = storage::get_active_signer_window(env);
+    if window == 0 {
+        return config.signers.len();
+    }
+    let mut active = 0;
+    for signer in config.signers.iter() {
+        if is_active_signer(env, &signer, window) {
+            active += 1;
+        }
+    }
+    if active == 0 {
+        config.signers.len()
+    } else {
+        active.max(config.threshold).min(config.signers.len())
+    }
+}
+
+/// Record a signer's approval or abstention for liveness tracking.
+fn record_vote(env: &Env, signer: &Address) {
+    let mut activity = storage::get_signer_activity(env, signer);
+    activity.last_vote = env.ledger().sequence() as u64;
+    storage::set_signer_activity(env, signer, &activity);
+}
+
+/// Sum of upcoming recurring amounts reserved against spending limits.
+///
+/// Counts active schedules with reservation enabled whose next payment falls
+/// within `horizon` ledgers (overdue ones included). `exclude` skips a schedule's
+/// own reservation when that schedule is the one being paid.
+fn reserved_amount(env: &Env, horizon: u64, exclude: Option<u64>) -> i128 {
+    let cutoff = env.ledger().sequence() as u64 + horizon;
+    let mut reserved = 0;
+    for id in storage::get_limit_reservations(env).iter() {
+        if Some(id) == exclude {
+            continue;
+        }
+        if let Ok(payment) = storage::get_recurring_payment(env, id) {
+            if payment.is_active && payment.next_payment_ledger <= cutoff {
+                reserved += payment.amount;
+            }
+        }
+    }
+    reserved
+}
+
+/// Amount spent in a limit window, measured as configured in `limit_window_mode`.
+fn window_spent(env: &Env, config: &Config, window: SpendWindow) -> i128 {
+    match (&config.limit_window_mode, window) {
+        (LimitWindowMode::Calendar, SpendWindow::Daily) => {
+            storage::get_daily_spent(env, storage::get_day_number(env))
+        }
+        (LimitWindowMode::Calendar, SpendWindow::Weekly) => {
+            storage::get_weekly_spent(env, storage::get_week_number(env))
+        }
+        (LimitWindowMode::Rolling, SpendWindow::Daily) => storage::get_rolling_spent(env, 24),
+        (LimitWindowMode::Rolling, SpendWindow::Weekly) => storage::get_rolling_spent(env, 168),
+    }
+}
+
+/// Remaining (daily, weekly) headroom after spending and reservations.
+fn limit_headroom(env: &Env, config: &Config, exclude: Option<u64>) -> (i128, i128) {
+    let daily = config.daily_limit
+        - window_spent(env, config, SpendWindow::Daily)
+        - reserved_amount(env, DAY_IN_LEDGERS, exclude);
+    let weekly = config.weekly_limit
+        - window_spent(env, config, SpendWindow::Weekly)
+        - reserved_amount(env, DAY_IN_LEDGERS * 7, exclude);
+    (daily, weekly)
+}
+
+/// Remaining budget of a category for the current period (`None` if uncapped).
+fn category_budget_remaining(
+    env: &Env,
+    budget: &BudgetCategory,
+    period: BudgetPeriod,
+) -> Option<i128> {
+    let (cap, spent) = match period {
+        BudgetPeriod::Monthly => (
+            budget.monthly_cap,
+            storage::get_monthly_spent(env, &budget.category, storage::get_month_number(env)),
+        ),
+        BudgetPeriod::Quarterly => (
+            budget.quarterly_cap,
+            storage::get_quarterly_spent(env, &budget.category, storage::get_quarter_number(env)),
+        ),
+    };
+    if cap > 0 {
+        Some(cap - spent)
+    } else {
+        None
+    }
+}
+
+/// Check a proposal amount against its category's monthly and quarterly caps.
+///
+/// Budgeting is opt-in: until the first category is configured, any tag is accepted.
+fn check_category_budget(env: &Env, category: &Symbol, amount: i128) -> Result<(), VaultError> {
+    let budget = match storage::get_budget(env, category) {
+        Some(budget) => budget,
+        None if storage::get_budget_categories(env).is_empty() => return Ok(()),
+        None => return Err(VaultError::UnknownBudgetCategory),
+    };
+
+    if let Some(remaining) = category_budget_remaining(env, &budget, BudgetPeriod::Monthly) {
+        if amount > remaining {
+            return Err(VaultError::ExceedsMonthlyBudget);
+        }
+    }
+    if let Some(remaining) = category_budget_remaining(env, &budget, BudgetPeriod::Quarterly) {
+        if amount > remaining {
+            return Err(VaultError::ExceedsQuarterlyBudget);
+        }
+    }
+    Ok(())
+}
+
+/// Assign a role, keeping the Admin count, membership lists and history in sync.
+///
+/// Rejects demoting the last Admin and time-limited Admin grants. Any
+/// permission override on the target is reset to the new role's preset.
+fn assign_role(
+    env: &Env,
+    changed_by: &Address,
+    target: &Address,
+    role: Role,
+    expires_at: Option<u64>,
+) -> Result<(), VaultError> {
+    if let Some(expires_at) = expires_at {
+        if role == Role::Admin || expires_at <= env.ledger().sequence() as u64 {
+            return Err(VaultError::InvalidRoleExpiry);
+        }
+    }
+
+    // Stored role, so a lapsed grant is recorded as what it was rather than Member
+    let assigned = storage::get_assigned_role(env, target);
+    let previous = assigned.clone().unwrap_or(Role::Member);
+    let was_admin = previous == Role::Admin;
+    let is_admin = role == Role::Admin;
+    let admin_count = storage::get_admin_count(env);
+    if was_admin && !is_admin {
+        if admin_count <= 1 {
+            return Err(VaultError::LastAdmin);
+        }
+        storage::set_admin_count(env, admin_count - 1);
+    } else if is_admin && !was_admin {
+        storage::set_admin_count(env, admin_count + 1);
+    }
+
+    let from = assigned.map(|r| r as u32);
+    storage::move_role_member(env, target, from, role.clone() as u32);
+    storage::add_role_change(
+        env,
+        target,
+        RoleChange {
+            previous,
+            role: role.clone(),
+            expires_at,
+            changed_by: changed_by.clone(),
+            ledger: env.ledger().sequence() as u64,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+
+    storage::set_role(env, target, role.clone());
+    storage::set_role_expiry(env, target, expires_at);
+    storage::clear_permissions(env, target);
+
+    events::emit_role_assigned(env, target, role as u32);
+    Ok(())
+}
+
+/// Record signer activity for the inactivity-triggered succession timer.
+///
+/// Calls by non-signers (and before initialization) are ignored.
+fn record_activity(env: &Env, addr: &Address) {
+    if let Ok(config) = storage::get_config(env) {
+        if config.signers.contains(addr) {
+            storage::set_last_activity(env, env.ledger().sequence() as u64);
+        }
+    }
+}
+
+/// Install a new signer set and threshold.
+///
+/// `new_admin` is promoted first so demoting removed Admins never hits
+/// `LastAdmin`; other new signers without voting rights become Treasurers.
+/// Every other address holding a role, signer or not, is reset to Member and
+/// every other permission override is cleared, so no old key keeps control.
+fn replace_signer_set(
+    env: &Env,
+    new_signers: &Vec<Address>,
+    threshold: u32,
+    new_admin: &Address,
+) -> Result<Config, VaultError> {
+    let old_config = storage::get_config(env)?;
+    let mut config = old_config.clone();
+    let contract = env.current_contract_address();
+
+    if storage::get_role(env, new_admin) != Role::Admin {
+        assign_role(env, &contract, new_admin, Role::Admin, None)?;
+    }
+    for signer in new_signers.iter() {
+        if !storage::has_permission(env, &signer, Permission::Approve) {
+            assign_role(env, &contract, &signer, Role::Treasurer, None)?;
+        }
+    }
+    for role in [Role::Treasurer, Role::Admin, Role::Guardian] {
+        for addr in storage::get_role_members(env, role as u32).iter() {
+            if addr != *new_admin
+                && !new_signers.contains(&addr)
+                && storage::get_assigned_role(env, &addr).is_some_and(|r| r != Role::Member)
+            {
+                assign_role(env, &contract, &addr, Role::Member, None)?;
+            }
+        }
+    }
+    for addr in storage::get_permission_overrides(env).iter() {
+        if addr != *new_admin && !new_signers.contains(&addr) {
+            storage::clear_permissions(env, &addr);
+        }
+    }
+
+    config.signers = new_signers.clone();
+    config.threshold = threshold;
+    validate_threshold_strategy(env, &config)?;
+    storage::set_config(env, &config);
+
+    events::emit_config_updated(env, &contract, &old_config, &config);
+    Ok(config)
+}
+
+/// Check that the threshold strategy and veto threshold fit a replacement
+/// signer set, so recovery and succession are rejected when proposed rather
+/// than when executed.
+fn validate_signer_set_change(
+    env: &Env,
+    signers: &Vec<Address>,
+    threshold: u32,
+) -> Result<(), VaultError> {
+    let mut config = storage::get_config(env)?;
+    config.signers = signers.clone();
+    config.threshold = threshold;
+    validate_threshold_strategy(env, &config)
+}
+
+/// Reject an address list containing the same address twice.
+fn check_unique(list: &Vec<Address>) -> Result<(), VaultError> {
+    for (i, addr) in list.iter().enumerate() {
+        if list.first_index_of(&addr) != Some(i as u32) {
+            return Err(VaultError::SignerAlreadyExists);
+        }
+    }
+    Ok(())
+}
+
+/// Validate a safeguard change, both when proposed and when applied.
+fn validate_safeguard_change(env: &Env, change: &SafeguardChange) -> Result<(), VaultError> {
+    match change {
+        SafeguardChange::RecoveryConfig(recovery_config) => {
+            if recovery_config.threshold < 1 {
+                return Err(VaultError::ThresholdTooLow);
+            }
+            if recovery_config.threshold > recovery_config.recovery_guardians.len() {
+                return Err(VaultError::ThresholdTooHigh);
+            }
+            check_unique(&recovery_config.recovery_guardians)?;
+            if recovery_config.delay < MIN_RECOVERY_DELAY {
+                return Err(VaultError::IntervalTooShort);
+            }
+        }
+        SafeguardChange::SuccessionPlan(plan) => {
+            if plan.successors.is_empty() && plan.beneficiary.is_none() {
+                return Err(VaultError::NoSigners);
+            }
+            if !plan.successors.is_empty() {
+                if plan.threshold < 1 {
+                    return Err(VaultError::ThresholdTooLow);
+                }
+                if plan.threshold > plan.successors.len() {
+                    return Err(VaultError::ThresholdTooHigh);
+                }
+                check_signer_count(env, plan.successors.len())?;
+                check_unique(&plan.successors)?;
+                validate_signer_set_change(env, &plan.successors, plan.threshold)?;
+            }
+            if plan.inactivity_period < MIN_INACTIVITY_PERIOD {
+                return Err(VaultError::IntervalTooShort);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Amount a vesting grant still owes its beneficiary.
+///
+/// Callers add the change to the token's running liability whenever a grant
+/// is updated, so succession never has to scan every grant.
+fn vesting_owed(grant: &VestingGrant) -> i128 {
+    match grant.status {
+        VestingStatus::Active | VestingStatus::Revoked => grant.total - grant.claimed,
+        VestingStatus::Pending | VestingStatus::Completed => 0,
+    }
+}
+
+/// Amount a stream still owes its recipient, tracked like `vesting_owed`.
+fn stream_owed(env: &Env, stream: &Stream) -> i128 {
+    match stream.status {
+        StreamStatus::Active => {
+            stream
+                .rate_per_ledger
+                .saturating_mul(stream.duration as i128)
+                - stream.withdrawn
+        }
+        StreamStatus::Cancelled => calculate_streamed_amount(env, stream) - stream.withdrawn,
+        StreamStatus::Pending | StreamStatus::Completed => 0,
+    }
+}
+
+/// Number of `votes` cast by current signers.
+///
+/// Votes from keys that were since rotated out or removed don't count, so a
+/// signer seat is never counted twice.
+fn count_signer_votes(config: &Config, votes: &Vec<Address>) -> u32 {
+    votes
+        .iter()
+        .filter(|voter| config.signers.contains(voter))
+        .count() as u32
+}
+
+/// Replace (or, with `new` = None, drop) `old` in a vote list.
+///
+/// Returns None if `old` is not in the list.
+fn replace_vote(list: &Vec<Address>, old: &Address, new: Option<&Address>) -> Option<Vec<Address>> {
+    let idx = list.first_index_of(old)?;
+    let mut updated = list.clone();
+    match new {
+        Some(new) if !list.contains(new) => updated.set(idx, new.clone()),
+        _ => {
+            updated.remove(idx);
+        }
+    }
+    Some(updated)
+}
+
+/// Validate spending limit and timelock invariants of a configuration.
+fn validate_limits(config: &Config) -> Result<(), VaultError> {
+    if config.spending_limit <= 0
+        || config.daily_limit <= 0
+        || config.weekly_limit <= 0
+        || config.timelock_threshold <= 0
+    {
+        return Err(VaultError::InvalidAmount);
+    }
+    if config.spending_limit > config.daily_limit || config.daily_limit > config.weekly_limit {
+        return Err(VaultError::LimitsOutOfOrder);
+    }
+    Ok(())
+}
+
+/// Validate that timelock tiers are sorted by amount with non-decreasing delays.
+fn validate_timelock_tiers(tiers: &Vec<TimelockTier>) -> Result<(), VaultError> {
+    let mut prev: Option<TimelockTier> = None;
+    for tier in tiers.iter() {
+        if tier.min_amount <= 0 {
+            return Err(VaultError::InvalidTimelockTiers);
+        }
+        if let Some(prev) = prev {
+            if tier.min_amount <= prev.min_amount || tier.delay < prev.delay {
+                return Err(VaultError::InvalidTimelockTiers);
+            }
+        }
+        prev = Some(tier);
+    }
+    Ok(())
+}
+
+/// Timelock delay for an amount, in ledgers (0 if no timelock applies).
+///
+/// Uses the highest matching tier when tiers are configured, otherwise the
+/// single `timelock_threshold`/`timelock_delay` pair from the config.
+fn timelock_delay_for(env: &Env, config: &Config, amount: i128) -> u64 {
+    let tiers = storage::get_timelock_tiers(env);
+    if tiers.is_empty() {
+        if amount >= config.timelock_threshold {
+            return config.timelock_delay;
+        }
+        return 0;
+    }
+
+    let mut delay = 0;
+    for tier in tiers.iter() {
+        if amount >= tier.min_amount {
+            delay = tier.delay;
+        }
+    }
+    delay
+}
+
+/// Remaining (recipient, proposer) headroom under the rolling 24h address caps.
+///
+/// Uncapped addresses report `i128::MAX`.
+fn address_headroom(env: &Env, recipient: &Address, proposer: &Address) -> (i128, i128) {
+    let caps = storage::get_address_caps(env);
+    let recipient_headroom = if caps.recipient_cap > 0 {
+        caps.recipient_cap - storage::get_recipient_spent(env, recipient)
+    } else {
+        i128::MAX
+    };
+    let proposer_headroom = if caps.proposer_cap > 0 {
+        caps.proposer_cap - storage::get_proposer_spent(env, proposer)
+    } else {
+        i128::MAX
+    };
+    (recipient_headroom, proposer_headroom)
+}
+
+/// Check an amount against the recipient's and proposer's rolling caps.
+fn check_address_caps(
+    env: &Env,
+    recipient: &Address,
+    proposer: &Address,
+    amount: i128,
+) -> Result<(), VaultError> {
+    let (recipient_headroom, proposer_headroom) = address_headroom(env, recipient, proposer);
+    if amount > recipient_headroom {
+        return Err(VaultError::ExceedsRecipientCap);
+    }
+    if amount > proposer_headroom {
+        return Err(VaultError::ExceedsProposerCap);
+    }
+    Ok(())
+}
+
+/// Reject tokens disabled in the registry, and tokens outside it while the
+/// registry is enforced.
+///
+/// Enforcement is off until an Admin turns it on with
+/// `set_token_registry_enforced`, so a new vault can register its tokens first.
+fn check_token_supported(env: &Env, token_addr: &Address) -> Result<(), VaultError> {
+    match storage::get_token_info(env, token_addr) {
+        Some(info) if info.enabled => Ok(()),
+        None if !storage::get_token_registry(env).enforced => Ok(()),
+        _ => Err(VaultError::TokenNotSupported),
+    }
+}
+
+/// Reject denylisted recipients, and recipients outside the address book when
+/// allowlist-only mode is on.
+fn check_recipient_allowed(
+    env: &Env,
+    config: &Config,
+    recipient: &Address,
+) -> Result<(), VaultError> {
+    if storage::is_denylisted(env, recipient) {
+        return Err(VaultError::RecipientDenylisted);
+    }
+    if config.allowlist_only && storage::get_address_book_entry(env, recipient).is_none() {
+        return Err(VaultError::RecipientNotAllowed);
+    }
+    Ok(())
+}
+
+/// Largest amount that can be paid out now under the daily/weekly limits and
+/// the address caps, for payouts that may settle part of what is owed.
+///
+/// Errors with the exhausted limit when nothing can be paid.
+fn payout_headroom(
+    env: &Env,
+    config: &Config,
+    recipient: &Address,
+    proposer: &Address,
+    reservation_owner: Option<u64>,
+) -> Result<i128, VaultError> {
+    let (daily_headroom, weekly_headroom) = limit_headroom(env, config, reservation_owner);
+    if daily_headroom <= 0 {
+        return Err(VaultError::ExceedsDailyLimit);
+    }
+    if weekly_headroom <= 0 {
+        return Err(VaultError::ExceedsWeeklyLimit);
+    }
+    let (recipient_headroom, proposer_headroom) = address_headroom(env, recipient, proposer);
+    if recipient_headroom <= 0 {
+        return Err(VaultError::ExceedsRecipientCap);
+    }
+    if proposer_headroom <= 0 {
+        return Err(VaultError::ExceedsProposerCap);
+    }
+    Ok(daily_headroom
+        .min(weekly_headroom)
+        .min(recipient_headroom)
+        .min(proposer_headroom))
+}
+
+/// Check that a payout fits the daily/weekly limits, the address caps and the
+/// vault balance.
+///
+/// `reservation_owner` is the recurring schedule being paid, whose own
+/// reservation does not count against it.
+fn check_payout(
+    env: &Env,
+    config: &Config,
+    recipient: &Address,
+    proposer: &Address,
+    token_addr: &Address,
+    amount: i128,
+    reservation_owner: Option<u64>,
+) -> Result<(), VaultError> {
+    let (daily_headroom, weekly_headroom) = limit_headroom(env, config, reservation_owner);
+    if amount > daily_headroom {
+        return Err(VaultError::ExceedsDailyLimit);
+    }
+    if amount > weekly_headroom {
+        return Err(VaultError::ExceedsWeeklyLimit);
+    }
+
+    check_address_caps(env, recipient, proposer, amount)?;
+
+    let balance = token::balance(env, token_addr);
+    if balance < amount {
+        return Err(VaultError::InsufficientBalance);
+    }
+
+    Ok(())
+}
+
+/// Process a single recurring payment if it is due.
+///
+/// Shared by `execute_recurring_payment` and `execute_due_recurring`. Errors are
+/// returned only when the schedule is missing, inactive or not yet due. If the
+/// payment is due but would breach a spending limit or the vault balance, the
+/// missed amount is recorded as arrears, the schedule advances, and the failure
+/// reason is returned as `Ok(Some(reason))`.
+fn run_recurring_payment(env: &Env, payment_id: u64) -> Result<Option<VaultError>, VaultError> {
+    let mut payment = storage::get_recurring_payment(env, payment_id)?;
+
+    if !payment.is_active {
+        return Err(VaultError::ProposalNotFound); // Or specific "NotActive" error
+    }
+
+    let current_ledger = env.ledger().sequence() as u64;
+    if current_ledger < payment.next_payment_ledger {
+        return Err(VaultError::TimelockNotExpired); // Reuse error for "Too Early"
+    }
+
+    if storage::is_denylisted(env, &payment.recipient) {
+        return Err(VaultError::RecipientDenylisted);
+    }
+    check_token_supported(env, &payment.token)?;
+
+    // Note: Recurring payments count towards limits!
+    let config = storage::get_config(env)?;
+    let outcome = match check_payout(
+        env,
+        &config,
+        &payment.recipient,
+        &payment.proposer,
+        &payment.token,
+        payment.amount,
+        Some(payment_id),
+    ) {
+        Ok(()) => {
+            token::transfer(env, &payment.token, &payment.recipient, payment.amount);
+            storage::add_spent(env, payment.amount);
+            storage::add_address_spent(env, &payment.recipient, &payment.proposer, payment.amount);
+            payment.payment_count += 1;
+            None
+        }
+        Err(reason) => {
+            payment.arrears += payment.amount;
+            payment.failed_count += 1;
+            payment.last_failure = reason as u32;
+            events::emit_recurring_failed(
+                env,
+                payment_id,
+                payment.amount,
+                reason as u32,
+                payment.arrears,
+            );
+            Some(reason)
+        }
+    };
+
+    // Advance schedule either way; a missed period is tracked in arrears
+    payment.next_payment_ledger += payment.interval;
+    storage::set_recurring_payment(env, &payment);
+    storage::extend_instance_ttl(env);
+
+    Ok(outcome)
+}
+
+/// Amount of a grant vested at the current ledger (cliff, then linear)
+fn calculate_vested_amount(env: &Env, grant: &VestingGrant) -> i128 {
+    match grant.status {
+        VestingStatus::Pending => return 0,
+        // Total is frozen at the vested amount on revocation
+        VestingStatus::Revoked | VestingStatus::Completed => return grant.total,
+        VestingStatus::Active => {}
+    }
+
+    let schedule = &grant.schedule;
+    let current_ledger = env.ledger().sequence() as u64;
+    let elapsed = current_ledger.saturating_sub(schedule.start_ledger);
+    if current_ledger < schedule.start_ledger || elapsed < schedule.cliff {
+        0
+    } else if elapsed >= schedule.duration {
+        grant.total
+    } else {
+        grant.total * elapsed as i128 / schedule.duration as i128
+    }
+}
+
+/// Amount a stream has accrued at the current ledger
+fn calculate_streamed_amount(env: &Env, stream: &Stream) -> i128 {
+    if stream.status == StreamStatus::Pending {
+        return 0;
+    }
+    let current_ledger = env.ledger().sequence() as u64;
+    let until = current_ledger.min(stream.end_ledger);
+    let elapsed = until.saturating_sub(stream.start_ledger);
+    // Bounded by the stream total, which is checked for overflow on proposal
+    stream.rate_per_ledger.saturating_mul(elapsed as i128)
+}
+
+/// Stop a pending or active stream's accrual at the current ledger.
+///
+/// The accrued amount stays withdrawable and the unstreamed remainder stays in
+/// the treasury. Returns `(owed, refunded)`.
+fn stop_stream(env: &Env, stream: &mut Stream) -> (i128, i128) {
+    let owed_before = stream_owed(env, stream);
+    let current_ledger = env.ledger().sequence() as u64;
+    let total = stream
+        .rate_per_ledger
+        .saturating_mul(stream.duration as i128);
+    if stream.status == StreamStatus::Pending {
+        stream.start_ledger = current_ledger;
+        stream.end_ledger = current_ledger;
+    } else {
+        stream.end_ledger = stream.end_ledger.min(current_ledger);
+    }
+
+    let streamed = calculate_streamed_amount(env, stream);
+    let owed = streamed - stream.withdrawn;
+    let refunded = total - streamed;
+
+    stream.status = if owed > 0 {
+        StreamStatus::Cancelled
+    } else {
+        StreamStatus::Completed
+    };
+    storage::add_liability(env, &stream.token, stream_owed(env, stream) - owed_before);
+    (owed, refunded)
+}
+
 #[contractimpl]
 impl VaultDAO {
     // ========================================================================
@@ -94,12 +878,11 @@ impl VaultDAO {
         if config.threshold > config.signers.len() {
             return Err(VaultError::ThresholdTooHigh);
         }
-        if config.spending_limit <= 0 || config.daily_limit <= 0 || config.weekly_limit <= 0 {
-            return Err(VaultError::InvalidAmount);
-        }
+        check_signer_count(&env, config.signers.len())?;
 
         // Admin must authorize initialization
         admin.require_auth();
+        record_activity(&env, &admin);
 
         // Create config
         let config_storage = Config {
@@ -110,12 +893,18 @@ impl VaultDAO {
             weekly_limit: config.weekly_limit,
             timelock_threshold: config.timelock_threshold,
             timelock_delay: config.timelock_delay,
+            limit_window_mode: config.limit_window_mode,
+            allowlist_only: config.allowlist_only,
+            timelock_clock: config.timelock_clock,
             threshold_strategy: config.threshold_strategy,
         };
+        validate_limits(&config_storage)?;
+        validate_threshold_strategy(&env, &config_storage)?;
 
         // Store state
         storage::set_config(&env, &config_storage);
-        storage::set_role(&env, &admin, Role::Admin);
+        assign_role(&env, &admin, &admin, Role::Admin, None)?;
+        storage::set_last_activity(&env, env.ledger().sequence() as u64);
         storage::set_initialized(&env);
         storage::extend_instance_ttl(&env);
 
@@ -140,9 +929,13 @@ impl VaultDAO {
     /// * `token_addr` - The contract ID of the Stellar Asset Contract (SAC) or custom token.
     /// * `amount` - The transaction amount (in stroops/smallest unit).
     /// * `memo` - A descriptive symbol for the transaction.
+    /// * `priority` - Queue priority of the proposal.
+    /// * `category` - Budget category; once any budget is configured, it must be
+    ///   a registered category and the amount must fit its monthly/quarterly caps.
     ///
     /// # Returns
     /// The unique ID of the newly created proposal.
+    #[allow(clippy::too_many_arguments)]
     pub fn propose_transfer(
         env: Env,
         proposer: Address,
@@ -151,16 +944,17 @@ impl VaultDAO {
         amount: i128,
         memo: Symbol,
         priority: Priority,
+        category: Symbol,
     ) -> Result<u64, VaultError> {
         // Verify identity
         proposer.require_auth();
+        record_activity(&env, &proposer);
 
         // Check initialization
         let config = storage::get_config(&env)?;
 
         // Check role
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
+        if !storage::has_permission(&env, &proposer, Permission::Propose) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -169,28 +963,34 @@ impl VaultDAO {
             return Err(VaultError::InvalidAmount);
         }
 
+        // Check recipient against the denylist and address book
+        check_recipient_allowed(&env, &config, &recipient)?;
+        check_token_supported(&env, &token_addr)?;
+
         // Check per-proposal spending limit
         if amount > config.spending_limit {
             return Err(VaultError::ExceedsProposalLimit);
         }
 
-        // Check daily aggregate limit
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + amount > config.daily_limit {
+        // Check daily and weekly aggregate limits, net of recurring reservations
+        let (daily_headroom, weekly_headroom) = limit_headroom(&env, &config, None);
+        if amount > daily_headroom {
             return Err(VaultError::ExceedsDailyLimit);
         }
-
-        // Check weekly aggregate limit
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + amount > config.weekly_limit {
+        if amount > weekly_headroom {
             return Err(VaultError::ExceedsWeeklyLimit);
         }
 
+        // Check category budgets
+        check_category_budget(&env, &category, amount)?;
+
+        // Check per-recipient and per-proposer caps
+        check_address_caps(&env, &recipient, &proposer, amount)?;
+
         // Reserve spending (will be confirmed on execution)
-        storage::add_daily_spent(&env, today, amount);
-        storage::add_weekly_spent(&env, week, amount);
+        storage::add_spent(&env, amount);
+        storage::add_category_spent(&env, &category, amount);
+        storage::add_address_spent(&env, &recipient, &proposer, amount);
 
         // Create proposal
         let proposal_id = storage::increment_proposal_id(&env);
@@ -208,13 +1008,21 @@ impl VaultDAO {
             status: ProposalStatus::Pending,
             priority: priority.clone(),
             attachments: Vec::new(&env),
+            category,
+            flagged: false,
+            vetoes: Vec::new(&env),
+            cancel_reason: None,
             created_at: current_ledger,
             expires_at: current_ledger + PROPOSAL_EXPIRY_LEDGERS,
             unlock_ledger: 0,
+            created_time: env.ledger().timestamp(),
+            expires_time: env.ledger().timestamp() + PROPOSAL_EXPIRY_SECONDS,
+            unlock_time: 0,
         };
 
         storage::set_proposal(&env, &proposal);
         storage::add_to_priority_queue(&env, priority as u32, proposal_id);
+        storage::add_recipient_proposal(&env, &recipient, proposal_id);
         storage::extend_instance_ttl(&env);
 
         // Emit event
@@ -227,7 +1035,8 @@ impl VaultDAO {
     ///
     /// Approval requires `require_auth()` from a valid signer.
     /// When the threshold is reached, the status changes to `Approved`.
-    /// If the amount exceeds the `timelock_threshold`, an `unlock_ledger` is calculated.
+    /// If the amount falls in a timelock tier (or exceeds the `timelock_threshold`
+    /// when no tiers are set), an `unlock_ledger` is calculated.
     ///
     /// # Arguments
     /// * `signer` - The authorized address providing approval.
@@ -235,6 +1044,7 @@ impl VaultDAO {
     pub fn approve_proposal(env: Env, signer: Address, proposal_id: u64) -> Result<(), VaultError> {
         // Verify identity - CRITICAL for security
         signer.require_auth();
+        record_activity(&env, &signer);
 
         // Get config and validate signer
         let config = storage::get_config(&env)?;
@@ -243,8 +1053,7 @@ impl VaultDAO {
         }
 
         // Check role (must be Treasurer or Admin)
-        let role = storage::get_role(&env, &signer);
-        if role != Role::Treasurer && role != Role::Admin {
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -257,8 +1066,7 @@ impl VaultDAO {
         }
 
         // Check expiration
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger > proposal.expires_at {
+        if is_expired(&env, &config, &proposal) {
             proposal.status = ProposalStatus::Expired;
             storage::set_proposal(&env, &proposal);
             return Err(VaultError::ProposalExpired);
@@ -276,21 +1084,25 @@ impl VaultDAO {
 
         // Add approval
         proposal.approvals.push_back(signer.clone());
+        record_vote(&env, &signer);
 
         // Check if threshold met using dynamic strategy
-        let approval_count = proposal.approvals.len();
+        let approval_count = count_signer_votes(&config, &proposal.approvals);
         let required_threshold = calculate_required_threshold(&env, &config, &proposal);
 
         if approval_count >= required_threshold {
             proposal.status = ProposalStatus::Approved;
 
             // Check for Timelock
-            if proposal.amount >= config.timelock_threshold {
-                let current_ledger = env.ledger().sequence() as u64;
-                proposal.unlock_ledger = current_ledger + config.timelock_delay;
-                // Note: We don't change status, but execute() will check unlock_ledger
+            let delay = timelock_delay_for(&env, &config, proposal.amount);
+            if delay > 0 {
+                let (delay_ledgers, delay_seconds) = delay_in_both_clocks(&config, delay);
+                proposal.unlock_ledger = env.ledger().sequence() as u64 + delay_ledgers;
+                proposal.unlock_time = env.ledger().timestamp() + delay_seconds;
+                // Note: We don't change status, but execute() will check the unlock point
             } else {
                 proposal.unlock_ledger = 0;
+                proposal.unlock_time = 0;
             }
 
             events::emit_proposal_ready(&env, proposal_id);
@@ -320,14 +1132,14 @@ impl VaultDAO {
         proposal_id: u64,
     ) -> Result<(), VaultError> {
         signer.require_auth();
+        record_activity(&env, &signer);
 
         let config = storage::get_config(&env)?;
         if !config.signers.contains(&signer) {
             return Err(VaultError::NotASigner);
         }
 
-        let role = storage::get_role(&env, &signer);
-        if role != Role::Treasurer && role != Role::Admin {
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
             return Err(VaultError::InsufficientRole);
         }
 
@@ -337,8 +1149,7 @@ impl VaultDAO {
             return Err(VaultError::ProposalNotPending);
         }
 
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger > proposal.expires_at {
+        if is_expired(&env, &config, &proposal) {
             proposal.status = ProposalStatus::Expired;
             storage::set_proposal(&env, &proposal);
             return Err(VaultError::ProposalExpired);
@@ -353,6 +1164,7 @@ impl VaultDAO {
         }
 
         proposal.abstentions.push_back(signer.clone());
+        record_vote(&env, &signer);
 
         storage::set_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
@@ -364,7 +1176,7 @@ impl VaultDAO {
 
     /// Finalizes and executes an approved proposal.
     ///
-    /// Can be called by anyone (even an automated tool) as long as:
+    /// Can be called by any address holding the `Execute` permission as long as:
     /// 1. The proposal status is `Approved`.
     /// 2. The required approvals threshold has been met.
     /// 3. Any applicable timelock has expired.
@@ -380,6 +1192,11 @@ impl VaultDAO {
     ) -> Result<(), VaultError> {
         // Executor must authorize (to prevent griefing)
         executor.require_auth();
+        record_activity(&env, &executor);
+
+        if !storage::has_permission(&env, &executor, Permission::Execute) {
+            return Err(VaultError::InsufficientRole);
+        }
 
         // Get proposal
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
@@ -393,18 +1210,24 @@ impl VaultDAO {
         }
 
         // Check expiration (even approved proposals can expire)
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger > proposal.expires_at {
+        let config = storage::get_config(&env)?;
+        if is_expired(&env, &config, &proposal) {
             proposal.status = ProposalStatus::Expired;
             storage::set_proposal(&env, &proposal);
             return Err(VaultError::ProposalExpired);
         }
 
         // Check Timelock
-        if proposal.unlock_ledger > 0 && current_ledger < proposal.unlock_ledger {
+        if is_timelocked(&env, &config, &proposal) {
             return Err(VaultError::TimelockNotExpired);
         }
 
+        // Check denylist (recipient may have been added after approval)
+        if storage::is_denylisted(&env, &proposal.recipient) {
+            return Err(VaultError::RecipientDenylisted);
+        }
+        check_token_supported(&env, &proposal.token)?;
+
         // Check vault balance
         let balance = token::balance(&env, &proposal.token);
         if balance < proposal.amount {
@@ -418,6 +1241,7 @@ impl VaultDAO {
         proposal.status = ProposalStatus::Executed;
         storage::set_proposal(&env, &proposal);
         storage::remove_from_priority_queue(&env, proposal.priority as u32, proposal_id);
+        storage::remove_recipient_proposal(&env, &proposal.recipient, proposal_id);
         storage::extend_instance_ttl(&env);
 
         // Emit event
@@ -441,12 +1265,14 @@ impl VaultDAO {
         proposal_id: u64,
     ) -> Result<(), VaultError> {
         rejector.require_auth();
+        record_activity(&env, &rejector);
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
         // Only Admin or proposer can reject
-        let role = storage::get_role(&env, &rejector);
-        if role != Role::Admin && rejector != proposal.proposer {
+        if !storage::has_permission(&env, &rejector, Permission::ManageProposals)
+            && rejector != proposal.proposer
+        {
             return Err(VaultError::Unauthorized);
         }
 
@@ -457,6 +1283,7 @@ impl VaultDAO {
         proposal.status = ProposalStatus::Rejected;
         storage::set_proposal(&env, &proposal);
         storage::remove_from_priority_queue(&env, proposal.priority as u32, proposal_id);
+        storage::remove_recipient_proposal(&env, &proposal.recipient, proposal_id);
 
         // Note: Daily spending is NOT refunded to prevent gaming
 
@@ -465,31 +1292,116 @@ impl VaultDAO {
         Ok(())
     }
 
-    // ========================================================================
-    // Attachment Management
-    // ========================================================================
-
-    /// Add an IPFS attachment to a proposal
+    /// Veto an approved proposal during its timelock
     ///
-    /// Only proposer or admin can add attachments to pending proposals.
-    pub fn add_attachment(
+    /// Any signer can veto while the timelock has not expired. Once the
+    /// veto threshold (default 1) is met the proposal moves to `Cancelled` and
+    /// can no longer be executed.
+    ///
+    /// # Returns
+    /// `true` if this veto cancelled the proposal.
+    pub fn veto_proposal(
         env: Env,
-        caller: Address,
+        signer: Address,
         proposal_id: u64,
-        ipfs_hash: soroban_sdk::String,
-    ) -> Result<(), VaultError> {
-        caller.require_auth();
-
-        // Validate IPFS hash length (CIDv0: 46 chars, CIDv1: variable, max ~100)
-        if ipfs_hash.len() < 10 || ipfs_hash.len() > 100 {
-            return Err(VaultError::InvalidAmount);
-        }
+        reason: Symbol,
+    ) -> Result<bool, VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let mut proposal = storage::get_proposal(&env, proposal_id)?;
+        if proposal.status != ProposalStatus::Approved || !is_timelocked(&env, &config, &proposal) {
+            return Err(VaultError::ProposalNotTimelocked);
+        }
+
+        if proposal.vetoes.contains(&signer) {
+            return Err(VaultError::AlreadyVetoed);
+        }
+        proposal.vetoes.push_back(signer.clone());
+        events::emit_proposal_vetoed(&env, proposal_id, &signer, &reason);
+
+        let cancelled =
+            count_signer_votes(&config, &proposal.vetoes) >= storage::get_veto_threshold(&env);
+        if cancelled {
+            proposal.status = ProposalStatus::Cancelled;
+            proposal.cancel_reason = Some(reason.clone());
+            storage::remove_from_priority_queue(
+                &env,
+                proposal.priority.clone() as u32,
+                proposal_id,
+            );
+            storage::remove_recipient_proposal(&env, &proposal.recipient, proposal_id);
+            events::emit_proposal_cancelled(&env, proposal_id, &reason);
+        }
+
+        storage::set_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+
+        Ok(cancelled)
+    }
+
+    /// Set how many signer vetoes cancel a timelocked proposal
+    ///
+    /// Only Admin can set. Must be between 1 and the number of signers.
+    pub fn set_veto_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let config = storage::get_config(&env)?;
+        if threshold < 1 {
+            return Err(VaultError::ThresholdTooLow);
+        }
+        if threshold > config.signers.len() {
+            return Err(VaultError::ThresholdTooHigh);
+        }
+
+        storage::set_veto_threshold(&env, threshold);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the number of vetoes required to cancel a timelocked proposal
+    pub fn get_veto_threshold(env: Env) -> u32 {
+        storage::get_veto_threshold(&env)
+    }
+
+    // ========================================================================
+    // Attachment Management
+    // ========================================================================
+
+    /// Add an IPFS attachment to a proposal
+    ///
+    /// Only proposer or admin can add attachments to pending proposals.
+    pub fn add_attachment(
+        env: Env,
+        caller: Address,
+        proposal_id: u64,
+        ipfs_hash: soroban_sdk::String,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        record_activity(&env, &caller);
+
+        // Validate IPFS hash length (CIDv0: 46 chars, CIDv1: variable, max ~100)
+        if ipfs_hash.len() < 10 || ipfs_hash.len() > 100 {
+            return Err(VaultError::InvalidAmount);
+        }
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
         // Only proposer or admin can add attachments
-        let role = storage::get_role(&env, &caller);
-        if role != Role::Admin && caller != proposal.proposer {
+        if !storage::has_permission(&env, &caller, Permission::Attach)
+            && caller != proposal.proposer
+        {
             return Err(VaultError::Unauthorized);
         }
 
@@ -522,12 +1434,14 @@ impl VaultDAO {
         ipfs_hash: soroban_sdk::String,
     ) -> Result<(), VaultError> {
         caller.require_auth();
+        record_activity(&env, &caller);
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
         // Only proposer or admin can remove attachments
-        let role = storage::get_role(&env, &caller);
-        if role != Role::Admin && caller != proposal.proposer {
+        if !storage::has_permission(&env, &caller, Permission::Attach)
+            && caller != proposal.proposer
+        {
             return Err(VaultError::Unauthorized);
         }
 
@@ -573,36 +1487,272 @@ impl VaultDAO {
 
     /// Set role for an address
     ///
-    /// Only Admin can assign roles.
+    /// Only Admin can assign roles. Promotion to Admin must go through
+    /// `propose_admin_grant`, and demoting the last Admin is rejected.
+    /// With `expires_at`, the role lapses to Member after that ledger.
     pub fn set_role(
         env: Env,
         admin: Address,
         target: Address,
         role: Role,
+        expires_at: Option<u64>,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageRoles) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        // Promotions to Admin go through propose_admin_grant
+        if role == Role::Admin && storage::get_role(&env, &target) != Role::Admin {
+            return Err(VaultError::Unauthorized);
+        }
+
+        assign_role(&env, &admin, &target, role, expires_at)?;
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Extend or clear the expiry of a time-limited role grant
+    ///
+    /// Only Admin can renew. A lapsed grant that has not yet been finalized by
+    /// `expire_role` is restored.
+    pub fn renew_role(
+        env: Env,
+        admin: Address,
+        target: Address,
+        expires_at: Option<u64>,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageRoles) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let role = storage::get_assigned_role(&env, &target).unwrap_or(Role::Member);
+        if role == Role::Member {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        assign_role(&env, &admin, &target, role, expires_at)?;
+        storage::extend_instance_ttl(&env);
+
+        events::emit_role_renewed(&env, &target, &admin, expires_at);
+
+        Ok(())
+    }
+
+    /// Finalize a lapsed time-limited role grant
+    ///
+    /// Can be called by anyone once the expiry ledger has passed. The role is
+    /// already treated as Member from that point; this records the change in
+    /// the membership lists and history and emits `role_expired`.
+    pub fn expire_role(env: Env, target: Address) -> Result<(), VaultError> {
+        let expires_at =
+            storage::get_role_expiry(&env, &target).ok_or(VaultError::InsufficientRole)?;
+        if !storage::role_lapsed(&env, &target) {
+            return Err(VaultError::TimelockNotExpired); // Reuse error for "not lapsed yet"
+        }
+
+        let role = storage::get_assigned_role(&env, &target).unwrap_or(Role::Member);
+        let contract = env.current_contract_address();
+        storage::move_role_member(
+            &env,
+            &target,
+            Some(role.clone() as u32),
+            Role::Member as u32,
+        );
+        storage::add_role_change(
+            &env,
+            &target,
+            RoleChange {
+                previous: role.clone(),
+                role: Role::Member,
+                expires_at: None,
+                changed_by: contract,
+                ledger: env.ledger().sequence() as u64,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        storage::set_role(&env, &target, Role::Member);
+        storage::set_role_expiry(&env, &target, None);
+        storage::clear_permissions(&env, &target);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_role_expired(&env, &target, role as u32, expires_at);
+
+        Ok(())
+    }
+
+    /// Get the expiry ledger of a time-limited role grant (None if permanent)
+    pub fn get_role_expiry(env: Env, addr: Address) -> Option<u64> {
+        storage::get_role_expiry(&env, &addr)
+    }
+
+    /// Propose promoting an address to Admin
+    ///
+    /// Requires `ManageRoles`. The grant is applied once `config.threshold`
+    /// signers approve it via `approve_admin_grant`.
+    pub fn propose_admin_grant(
+        env: Env,
+        proposer: Address,
+        target: Address,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        if !storage::has_permission(&env, &proposer, Permission::ManageRoles) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let id = storage::increment_admin_grant_id(&env);
+        let grant = AdminGrant {
+            id,
+            proposer: proposer.clone(),
+            target: target.clone(),
+            approvals: Vec::new(&env),
+            executed: false,
+        };
+        storage::set_admin_grant(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_admin_grant_proposed(&env, id, &proposer, &target);
+
+        Ok(id)
+    }
+
+    /// Approve a pending Admin grant
+    ///
+    /// Requires a signer with `Approve`. The target becomes Admin when
+    /// `config.threshold` approvals are reached.
+    pub fn approve_admin_grant(env: Env, signer: Address, grant_id: u64) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        let mut grant = storage::get_admin_grant(&env, grant_id)?;
+        if grant.executed {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+        if grant.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        grant.approvals.push_back(signer.clone());
+        let approval_count = count_signer_votes(&config, &grant.approvals);
+        if approval_count >= config.threshold {
+            assign_role(&env, &signer, &grant.target, Role::Admin, None)?;
+            grant.executed = true;
+        }
+
+        storage::set_admin_grant(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_admin_grant_approved(&env, grant_id, &signer, approval_count);
+
+        Ok(())
+    }
+
+    /// Get an Admin grant by ID
+    pub fn get_admin_grant(env: Env, grant_id: u64) -> Result<AdminGrant, VaultError> {
+        storage::get_admin_grant(&env, grant_id)
+    }
+
+    /// List addresses that have been assigned a role
+    ///
+    /// Addresses never assigned a role are implicit Members and are not listed.
+    pub fn list_addresses_with_role(env: Env, role: Role) -> Vec<Address> {
+        let mut members = Vec::new(&env);
+        for addr in storage::get_role_members(&env, role.clone() as u32).iter() {
+            // Skip lapsed grants not yet finalized by expire_role
+            if storage::get_role(&env, &addr) == role {
+                members.push_back(addr);
+            }
+        }
+        members
+    }
+
+    /// Get the role change history of an address, oldest first
+    pub fn get_role_history(env: Env, addr: Address) -> Vec<RoleChange> {
+        storage::get_role_history(&env, &addr)
+    }
+
+    /// Get the number of addresses holding the Admin role
+    pub fn get_admin_count(env: Env) -> u32 {
+        storage::get_admin_count(&env)
+    }
+
+    /// Override the permission mask of an address
+    ///
+    /// Requires `ManageRoles`. The mask replaces the role preset until the next
+    /// `set_role` for the address. Admins and the `ManageRoles` bit cannot be
+    /// overridden.
+    pub fn set_permissions(
+        env: Env,
+        admin: Address,
+        target: Address,
+        permissions: u32,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let caller_role = storage::get_role(&env, &admin);
-        if caller_role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageRoles) {
             return Err(VaultError::Unauthorized);
         }
+        // ManageRoles is only held through the multisig-granted Admin role,
+        // and Admins always keep the full preset
+        if permissions & !ALL_PERMISSIONS != 0
+            || permissions & Permission::ManageRoles as u32 != 0
+            || storage::get_role(&env, &target) == Role::Admin
+        {
+            return Err(VaultError::InvalidPermissions);
+        }
 
-        storage::set_role(&env, &target, role.clone());
+        storage::set_permissions(&env, &target, permissions);
         storage::extend_instance_ttl(&env);
 
-        events::emit_role_assigned(&env, &target, role as u32);
+        events::emit_permissions_set(&env, &target, &admin, permissions);
 
         Ok(())
     }
 
+    /// Get the effective permission mask of an address
+    pub fn get_permissions(env: Env, addr: Address) -> u32 {
+        storage::get_permissions(&env, &addr)
+    }
+
+    /// Check whether an address holds a permission
+    pub fn has_permission(env: Env, addr: Address, permission: Permission) -> bool {
+        storage::has_permission(&env, &addr, permission)
+    }
+
     /// Add a new signer
     ///
-    /// Only Admin can add signers.
-    pub fn add_signer(env: Env, admin: Address, new_signer: Address) -> Result<(), VaultError> {
+    /// Only Admin can add signers, up to the configured maximum. If `role` is
+    /// given it is assigned in the same call (requires `ManageRoles`; Admin
+    /// must still go through `propose_admin_grant`), so the new signer can vote
+    /// immediately.
+    pub fn add_signer(
+        env: Env,
+        admin: Address,
+        new_signer: Address,
+        role: Option<Role>,
+    ) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
             return Err(VaultError::Unauthorized);
         }
 
@@ -612,8 +1762,19 @@ impl VaultDAO {
         if config.signers.contains(&new_signer) {
             return Err(VaultError::SignerAlreadyExists);
         }
+        check_signer_count(&env, config.signers.len() + 1)?;
+
+        if let Some(role) = role {
+            if !storage::has_permission(&env, &admin, Permission::ManageRoles)
+                || (role == Role::Admin && storage::get_role(&env, &new_signer) != Role::Admin)
+            {
+                return Err(VaultError::Unauthorized);
+            }
+            assign_role(&env, &admin, &new_signer, role, None)?;
+        }
 
         config.signers.push_back(new_signer.clone());
+        validate_threshold_strategy(&env, &config)?;
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
@@ -624,12 +1785,21 @@ impl VaultDAO {
 
     /// Remove a signer
     ///
-    /// Only Admin can remove signers. Cannot reduce below threshold.
-    pub fn remove_signer(env: Env, admin: Address, signer: Address) -> Result<(), VaultError> {
+    /// Only Admin can remove signers. Cannot reduce below the highest threshold
+    /// the strategy can require (e.g. the largest `AmountBased` tier) or the
+    /// veto threshold. With
+    /// `downgrade`, the removed signer's role is also reset to Member (requires
+    /// `ManageRoles`; the last Admin cannot be downgraded).
+    pub fn remove_signer(
+        env: Env,
+        admin: Address,
+        signer: Address,
+        downgrade: bool,
+    ) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
             return Err(VaultError::Unauthorized);
         }
 
@@ -646,13 +1816,21 @@ impl VaultDAO {
 
         let idx = found_idx.ok_or(VaultError::SignerNotFound)?;
 
-        // Check if removal would make threshold unreachable
-        if config.signers.len() - 1 < config.threshold {
+        // Check if removal would make any threshold unreachable
+        if config.signers.len() - 1 < max_required_approvals(&env, &config) {
             return Err(VaultError::CannotRemoveSigner);
         }
 
+        if downgrade {
+            if !storage::has_permission(&env, &admin, Permission::ManageRoles) {
+                return Err(VaultError::Unauthorized);
+            }
+            assign_role(&env, &admin, &signer, Role::Member, None)?;
+        }
+
         // Remove signer
         config.signers.remove(idx);
+        validate_threshold_strategy(&env, &config)?;
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
@@ -661,6 +1839,104 @@ impl VaultDAO {
         Ok(())
     }
 
+    /// Replace a signer key in a single call
+    ///
+    /// Requires `ManageSigners` and `ManageRoles`, and authorization from the
+    /// old key. The new key takes the old key's position in the signer set, its
+    /// role (including any expiry), its permission overrides and its liveness
+    /// record; the old key is reset to Member. On open proposals the old key's
+    /// approvals, abstentions and vetoes are moved to the new key when
+    /// `transfer_votes` is set; otherwise they are dropped from pending
+    /// proposals and approved ones are left as they are. Votes left under the
+    /// old key elsewhere no longer count, since thresholds only count current
+    /// signers. The threshold is unchanged.
+    pub fn rotate_signer(
+        env: Env,
+        admin: Address,
+        old: Address,
+        new: Address,
+        transfer_votes: bool,
+    ) -> Result<u32, VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+        // The old key must consent, so its role cannot be copied to an arbitrary key
+        if old != admin {
+            old.require_auth();
+        }
+
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners)
+            || !storage::has_permission(&env, &admin, Permission::ManageRoles)
+        {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut config = storage::get_config(&env)?;
+        let idx = config
+            .signers
+            .first_index_of(&old)
+            .ok_or(VaultError::SignerNotFound)?;
+        if config.signers.contains(&new) {
+            return Err(VaultError::SignerAlreadyExists);
+        }
+        config.signers.set(idx, new.clone());
+        storage::set_config(&env, &config);
+
+        // Move the role before demoting the old key so the Admin count never drops
+        let role = storage::get_role(&env, &old);
+        let expires_at = storage::get_role_expiry(&env, &old);
+        let overrides = storage::get_permission_override(&env, &old);
+        if role != Role::Member {
+            assign_role(&env, &admin, &new, role, expires_at)?;
+            assign_role(&env, &admin, &old, Role::Member, None)?;
+        }
+        // assign_role clears overrides, so they are moved after the role
+        if let Some(permissions) = overrides {
+            storage::set_permissions(&env, &new, permissions);
+            storage::clear_permissions(&env, &old);
+        }
+        // Keep the seat's liveness for the active-signer window
+        storage::set_signer_activity(&env, &new, &storage::get_signer_activity(&env, &old));
+        storage::remove_signer_activity(&env, &old);
+
+        // Carry over or drop votes on open proposals
+        let replacement = if transfer_votes { Some(&new) } else { None };
+        let mut updated = 0;
+        for priority in 0..=Priority::Critical as u32 {
+            for proposal_id in storage::get_proposals_by_priority(&env, priority).iter() {
+                let Ok(mut proposal) = storage::get_proposal(&env, proposal_id) else {
+                    continue;
+                };
+                let open = proposal.status == ProposalStatus::Pending
+                    || (transfer_votes && proposal.status == ProposalStatus::Approved);
+                if !open {
+                    continue;
+                }
+                let approvals = replace_vote(&proposal.approvals, &old, replacement);
+                let abstentions = replace_vote(&proposal.abstentions, &old, replacement);
+                let vetoes = replace_vote(&proposal.vetoes, &old, replacement);
+                if approvals.is_none() && abstentions.is_none() && vetoes.is_none() {
+                    continue;
+                }
+                if let Some(approvals) = approvals {
+                    proposal.approvals = approvals;
+                }
+                if let Some(abstentions) = abstentions {
+                    proposal.abstentions = abstentions;
+                }
+                if let Some(vetoes) = vetoes {
+                    proposal.vetoes = vetoes;
+                }
+                storage::set_proposal(&env, &proposal);
+                updated += 1;
+            }
+        }
+        storage::extend_instance_ttl(&env);
+
+        events::emit_signer_rotated(&env, &old, &new, updated);
+
+        Ok(updated)
+    }
+
     /// Update spending limits
     ///
     /// Only Admin can update limits.
@@ -671,9 +1947,9 @@ impl VaultDAO {
         daily_limit: i128,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
             return Err(VaultError::Unauthorized);
         }
 
@@ -681,158 +1957,1720 @@ impl VaultDAO {
             return Err(VaultError::InvalidAmount);
         }
 
-        let mut config = storage::get_config(&env)?;
+        let old_config = storage::get_config(&env)?;
+        let mut config = old_config.clone();
         config.spending_limit = spending_limit;
         config.daily_limit = daily_limit;
+        validate_limits(&config)?;
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
-        events::emit_config_updated(&env, &admin);
+        events::emit_config_updated(&env, &admin, &old_config, &config);
 
         Ok(())
     }
 
-    /// Update threshold
+    /// Update any subset of limits and timelock parameters
     ///
-    /// Only Admin can update threshold.
-    pub fn update_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+    /// Only Admin can update config. Fields left as `None` keep their current
+    /// value. The resulting config must satisfy
+    /// `spending_limit <= daily_limit <= weekly_limit` and `timelock_threshold > 0`.
+    /// Allowlist-only mode is toggled through `propose_address_book` instead.
+    pub fn update_config(env: Env, admin: Address, update: ConfigUpdate) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
             return Err(VaultError::Unauthorized);
         }
 
-        let mut config = storage::get_config(&env)?;
-
-        if threshold < 1 {
-            return Err(VaultError::ThresholdTooLow);
+        let old_config = storage::get_config(&env)?;
+        let mut config = old_config.clone();
+        if let Some(spending_limit) = update.spending_limit {
+            config.spending_limit = spending_limit;
         }
-        if threshold > config.signers.len() {
-            return Err(VaultError::ThresholdTooHigh);
+        if let Some(daily_limit) = update.daily_limit {
+            config.daily_limit = daily_limit;
         }
+        if let Some(weekly_limit) = update.weekly_limit {
+            config.weekly_limit = weekly_limit;
+        }
+        if let Some(timelock_threshold) = update.timelock_threshold {
+            config.timelock_threshold = timelock_threshold;
+        }
+        if let Some(timelock_delay) = update.timelock_delay {
+            config.timelock_delay = timelock_delay;
+        }
+        validate_limits(&config)?;
 
-        config.threshold = threshold;
         storage::set_config(&env, &config);
         storage::extend_instance_ttl(&env);
 
-        events::emit_config_updated(&env, &admin);
+        events::emit_config_updated(&env, &admin, &old_config, &config);
 
         Ok(())
     }
 
-    // ========================================================================
-    // View Functions
-    // ========================================================================
-
-    // ========================================================================
-    // Recurring Payments
-    // ========================================================================
-
-    /// Schedule a new recurring payment
+    /// Replace the amount-based timelock tiers
     ///
-    /// Only Treasurer or Admin can schedule.
-    pub fn schedule_payment(
+    /// Only Admin can set tiers. Tiers must be ordered by strictly increasing
+    /// `min_amount` with non-decreasing delays. An empty list falls back to the
+    /// single `timelock_threshold`/`timelock_delay` from the config.
+    pub fn set_timelock_tiers(
         env: Env,
-        proposer: Address,
-        recipient: Address,
-        token_addr: Address,
-        amount: i128,
-        memo: Symbol,
-        interval: u64,
-    ) -> Result<u64, VaultError> {
-        proposer.require_auth();
+        admin: Address,
+        tiers: Vec<TimelockTier>,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &proposer);
-        if role != Role::Treasurer && role != Role::Admin {
-            return Err(VaultError::InsufficientRole);
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
+            return Err(VaultError::Unauthorized);
         }
 
-        if amount <= 0 {
-            return Err(VaultError::InvalidAmount);
-        }
+        validate_timelock_tiers(&tiers)?;
 
-        // Minimum interval check (e.g. 1 hour = 720 ledgers)
+        storage::set_timelock_tiers(&env, &tiers);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_timelock_tiers_updated(&env, &admin, tiers.len());
+
+        Ok(())
+    }
+
+    /// Get the amount-based timelock tiers
+    pub fn get_timelock_tiers(env: Env) -> Vec<TimelockTier> {
+        storage::get_timelock_tiers(&env)
+    }
+
+    /// Set rolling 24h caps applied to every recipient and every proposer
+    ///
+    /// Only Admin can set caps. A cap of 0 disables that check. Caps apply to
+    /// transfer proposals and recurring payments.
+    pub fn set_address_caps(
+        env: Env,
+        admin: Address,
+        recipient_cap: i128,
+        proposer_cap: i128,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if recipient_cap < 0 || proposer_cap < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        storage::set_address_caps(
+            &env,
+            &AddressCaps {
+                recipient_cap,
+                proposer_cap,
+            },
+        );
+        storage::extend_instance_ttl(&env);
+
+        events::emit_address_caps_updated(&env, &admin, recipient_cap, proposer_cap);
+
+        Ok(())
+    }
+
+    /// Set monthly and quarterly caps for a budget category
+    ///
+    /// Only Admin can set budgets. A cap of 0 leaves that period uncapped. Once
+    /// any category exists, proposals must be tagged with a registered category.
+    pub fn set_budget(
+        env: Env,
+        admin: Address,
+        category: Symbol,
+        monthly_cap: i128,
+        quarterly_cap: i128,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if monthly_cap < 0 || quarterly_cap < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let budget = BudgetCategory {
+            category: category.clone(),
+            monthly_cap,
+            quarterly_cap,
+        };
+        storage::set_budget(&env, &budget);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_budget_set(&env, &category, &admin, monthly_cap, quarterly_cap);
+
+        Ok(())
+    }
+
+    /// Update threshold
+    ///
+    /// Only Admin can update threshold.
+    pub fn update_threshold(env: Env, admin: Address, threshold: u32) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let old_config = storage::get_config(&env)?;
+        let mut config = old_config.clone();
+        config.threshold = threshold;
+        validate_threshold_strategy(&env, &config)?;
+        storage::set_config(&env, &config);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_config_updated(&env, &admin, &old_config, &config);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // View Functions
+    // ========================================================================
+
+    // ========================================================================
+    // Recurring Payments
+    // ========================================================================
+
+    /// Schedule a new recurring payment
+    ///
+    /// Only Treasurer or Admin can schedule.
+    pub fn schedule_payment(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        amount: i128,
+        memo: Symbol,
+        interval: u64,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        if !storage::has_permission(&env, &proposer, Permission::Propose) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        if amount <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let config = storage::get_config(&env)?;
+        check_recipient_allowed(&env, &config, &recipient)?;
+        check_token_supported(&env, &token_addr)?;
+
+        // Minimum interval check (e.g. 1 hour = 720 ledgers)
         if interval < 720 {
             return Err(VaultError::IntervalTooShort);
         }
 
-        let id = storage::increment_recurring_id(&env);
-        let current_ledger = env.ledger().sequence() as u64;
+        let id = storage::increment_recurring_id(&env);
+        let current_ledger = env.ledger().sequence() as u64;
+
+        let payment = crate::types::RecurringPayment {
+            id,
+            proposer: proposer.clone(),
+            recipient,
+            token: token_addr,
+            amount,
+            memo,
+            interval,
+            next_payment_ledger: current_ledger + interval,
+            payment_count: 0,
+            is_active: true,
+            arrears: 0,
+            failed_count: 0,
+            last_failure: 0,
+            reserve_limits: false,
+        };
+
+        storage::set_recurring_payment(&env, &payment);
+        storage::add_recipient_recurring(&env, &payment.recipient, id);
+
+        // Use a generic event or add a specific one (skipping specific event for brevity/limit)
+
+        Ok(id)
+    }
+
+    /// Execute a scheduled recurring payment
+    ///
+    /// Can be called by anyone (keeper/bot) if the schedule is due.
+    ///
+    /// If the payment would exceed a spending limit or the vault balance, the
+    /// missed amount is added to the schedule's arrears instead of failing.
+    ///
+    /// # Returns
+    /// `true` if the payment was made, `false` if it was recorded as arrears.
+    pub fn execute_recurring_payment(env: Env, payment_id: u64) -> Result<bool, VaultError> {
+        Ok(run_recurring_payment(&env, payment_id)?.is_none())
+    }
+
+    /// Execute a batch of scheduled recurring payments
+    ///
+    /// Can be called by anyone (keeper/bot). Schedules that are not due or inactive
+    /// are skipped instead of aborting the batch; those that would breach
+    /// limits/balance are recorded as arrears.
+    ///
+    /// # Returns
+    /// One result per requested ID, in the same order.
+    pub fn execute_due_recurring(env: Env, payment_ids: Vec<u64>) -> Vec<RecurringExecResult> {
+        let mut results = Vec::new(&env);
+        for payment_id in payment_ids.iter() {
+            let error_code = match run_recurring_payment(&env, payment_id) {
+                Ok(None) => 0,
+                Ok(Some(reason)) => reason as u32,
+                Err(err) => err as u32,
+            };
+            results.push_back(RecurringExecResult {
+                payment_id,
+                executed: error_code == 0,
+                error_code,
+            });
+        }
+        results
+    }
+
+    /// Pay out the arrears accumulated by a recurring payment
+    ///
+    /// Can be called by anyone (keeper/bot) once funds and limit headroom are
+    /// available. Settles as much of the outstanding amount as the current
+    /// limit headroom allows; any remainder stays in arrears.
+    ///
+    /// # Returns
+    /// The amount settled.
+    pub fn settle_arrears(env: Env, payment_id: u64) -> Result<i128, VaultError> {
+        let mut payment = storage::get_recurring_payment(&env, payment_id)?;
+        if payment.arrears <= 0 {
+            return Err(VaultError::NothingToClaim);
+        }
+
+        if storage::is_denylisted(&env, &payment.recipient) {
+            return Err(VaultError::RecipientDenylisted);
+        }
+        check_token_supported(&env, &payment.token)?;
+
+        let config = storage::get_config(&env)?;
+        let amount = payment.arrears.min(payout_headroom(
+            &env,
+            &config,
+            &payment.recipient,
+            &payment.proposer,
+            Some(payment_id),
+        )?);
+        let balance = token::balance(&env, &payment.token);
+        if balance < amount {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        token::transfer(&env, &payment.token, &payment.recipient, amount);
+        storage::add_spent(&env, amount);
+        storage::add_address_spent(&env, &payment.recipient, &payment.proposer, amount);
+
+        payment.arrears -= amount;
+        storage::set_recurring_payment(&env, &payment);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_arrears_settled(&env, payment_id, &payment.recipient, amount);
+
+        Ok(amount)
+    }
+
+    /// Enable or disable limit reservation for a recurring payment
+    ///
+    /// Only Admin can toggle. While enabled, the schedule's next amount is held
+    /// back from daily/weekly headroom once it falls due within that window, so
+    /// ad-hoc proposals cannot starve it.
+    pub fn set_recurring_reservation(
+        env: Env,
+        admin: Address,
+        payment_id: u64,
+        enabled: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageLimits) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut payment = storage::get_recurring_payment(&env, payment_id)?;
+        if payment.reserve_limits != enabled {
+            payment.reserve_limits = enabled;
+            storage::set_recurring_payment(&env, &payment);
+            if enabled {
+                storage::add_limit_reservation(&env, payment_id);
+            } else {
+                storage::remove_limit_reservation(&env, payment_id);
+            }
+        }
+        storage::extend_instance_ttl(&env);
+
+        events::emit_recurring_reservation_updated(&env, payment_id, &admin, enabled);
+
+        Ok(())
+    }
+
+    /// Get the (daily, weekly) amounts currently reserved by recurring payments
+    pub fn get_reserved_limits(env: Env) -> (i128, i128) {
+        (
+            reserved_amount(&env, DAY_IN_LEDGERS, None),
+            reserved_amount(&env, DAY_IN_LEDGERS * 7, None),
+        )
+    }
+
+    /// Get recurring payment by ID
+    pub fn get_recurring_payment(
+        env: Env,
+        payment_id: u64,
+    ) -> Result<crate::types::RecurringPayment, VaultError> {
+        storage::get_recurring_payment(&env, payment_id)
+    }
+
+    // ========================================================================
+    // Vesting
+    // ========================================================================
+
+    /// Propose a cliff-then-linear vesting grant
+    ///
+    /// Only Treasurer or Admin can propose. The grant starts releasing tokens once
+    /// `config.threshold` signers have approved it via `approve_vesting`. Tokens
+    /// are not escrowed: claims are paid from the vault balance at claim time.
+    /// `total` must fit the per-proposal spending limit.
+    ///
+    /// # Arguments
+    /// * `proposer` - The address proposing the grant (must authorize).
+    /// * `beneficiary` - The address that may claim vested tokens.
+    /// * `token_addr` - The token contract to pay out.
+    /// * `total` - Total amount granted.
+    /// * `schedule` - Start ledger, cliff and duration (in ledgers).
+    /// * `revocable` - Whether an admin may later revoke the unvested remainder.
+    ///
+    /// # Returns
+    /// The unique ID of the new vesting grant.
+    pub fn propose_vesting(
+        env: Env,
+        proposer: Address,
+        beneficiary: Address,
+        token_addr: Address,
+        total: i128,
+        schedule: VestingSchedule,
+        revocable: bool,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        let config = storage::get_config(&env)?;
+
+        if !storage::has_permission(&env, &proposer, Permission::Propose) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        check_recipient_allowed(&env, &config, &beneficiary)?;
+        check_token_supported(&env, &token_addr)?;
+
+        if total <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if schedule.duration == 0 || schedule.cliff > schedule.duration {
+            return Err(VaultError::InvalidVestingSchedule);
+        }
+        if total > config.spending_limit {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        let id = storage::increment_vesting_id(&env);
+        let grant = VestingGrant {
+            id,
+            proposer: proposer.clone(),
+            beneficiary: beneficiary.clone(),
+            token: token_addr,
+            total,
+            claimed: 0,
+            schedule,
+            revocable,
+            approvals: Vec::new(&env),
+            status: VestingStatus::Pending,
+        };
+
+        storage::set_vesting(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_vesting_created(&env, id, &proposer, &beneficiary, total);
+
+        Ok(id)
+    }
+
+    /// Approve a pending vesting grant
+    ///
+    /// Requires a signer with Treasurer or Admin role. When `config.threshold`
+    /// approvals are reached, the grant becomes `Active`.
+    pub fn approve_vesting(env: Env, signer: Address, vesting_id: u64) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        let mut grant = storage::get_vesting(&env, vesting_id)?;
+        let owed_before = vesting_owed(&grant);
+        if grant.status != VestingStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if grant.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        grant.approvals.push_back(signer.clone());
+        let approval_count = count_signer_votes(&config, &grant.approvals);
+        if approval_count >= config.threshold {
+            grant.status = VestingStatus::Active;
+            events::emit_vesting_activated(&env, vesting_id);
+        }
+
+        storage::add_liability(&env, &grant.token, vesting_owed(&grant) - owed_before);
+        storage::set_vesting(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_vesting_approved(&env, vesting_id, &signer, approval_count);
+
+        Ok(())
+    }
+
+    /// Claim currently vested, unclaimed tokens
+    ///
+    /// Only the beneficiary can claim. Revoked grants remain claimable up to the
+    /// amount vested at revocation. Claims count toward the daily and weekly
+    /// limits and the address caps; if the full vested amount does not fit, only
+    /// the remaining headroom is paid out and the rest stays claimable.
+    ///
+    /// # Returns
+    /// The amount transferred to the beneficiary.
+    pub fn claim_vested(
+        env: Env,
+        beneficiary: Address,
+        vesting_id: u64,
+    ) -> Result<i128, VaultError> {
+        beneficiary.require_auth();
+        record_activity(&env, &beneficiary);
+
+        let mut grant = storage::get_vesting(&env, vesting_id)?;
+        let owed_before = vesting_owed(&grant);
+        if grant.beneficiary != beneficiary {
+            return Err(VaultError::Unauthorized);
+        }
+        if grant.status != VestingStatus::Active && grant.status != VestingStatus::Revoked {
+            return Err(VaultError::VestingNotActive);
+        }
+        if storage::is_denylisted(&env, &beneficiary) {
+            return Err(VaultError::RecipientDenylisted);
+        }
+
+        let claimable = calculate_vested_amount(&env, &grant) - grant.claimed;
+        if claimable <= 0 {
+            return Err(VaultError::NothingToClaim);
+        }
+        check_token_supported(&env, &grant.token)?;
+
+        let config = storage::get_config(&env)?;
+        let claimable = claimable.min(payout_headroom(
+            &env,
+            &config,
+            &grant.beneficiary,
+            &grant.proposer,
+            None,
+        )?);
+
+        let balance = token::balance(&env, &grant.token);
+        if balance < claimable {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        token::transfer(&env, &grant.token, &beneficiary, claimable);
+        storage::add_spent(&env, claimable);
+        storage::add_address_spent(&env, &grant.beneficiary, &grant.proposer, claimable);
+
+        grant.claimed += claimable;
+        if grant.claimed >= grant.total {
+            grant.status = VestingStatus::Completed;
+        }
+        storage::add_liability(&env, &grant.token, vesting_owed(&grant) - owed_before);
+        storage::set_vesting(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_vesting_claimed(&env, vesting_id, &beneficiary, claimable);
+
+        Ok(claimable)
+    }
+
+    /// Revoke a vesting grant
+    ///
+    /// Only Admin can revoke, and only grants created as revocable; revoking any
+    /// other grant is `Unauthorized`. The grant stops vesting; tokens vested so
+    /// far stay claimable by the beneficiary. Since grants are not escrowed, no
+    /// tokens move: the unvested remainder is simply no longer owed.
+    ///
+    /// # Returns
+    /// The unvested amount the vault no longer owes.
+    pub fn revoke_vesting(env: Env, admin: Address, vesting_id: u64) -> Result<i128, VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageProposals) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut grant = storage::get_vesting(&env, vesting_id)?;
+        let owed_before = vesting_owed(&grant);
+        if !grant.revocable {
+            return Err(VaultError::Unauthorized);
+        }
+        if grant.status != VestingStatus::Pending && grant.status != VestingStatus::Active {
+            return Err(VaultError::VestingNotActive);
+        }
+
+        let vested = calculate_vested_amount(&env, &grant);
+        let unvested = grant.total - vested;
+
+        grant.total = vested;
+        grant.status = if grant.claimed >= vested {
+            VestingStatus::Completed
+        } else {
+            VestingStatus::Revoked
+        };
+        storage::add_liability(&env, &grant.token, vesting_owed(&grant) - owed_before);
+        storage::set_vesting(&env, &grant);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_vesting_revoked(&env, vesting_id, &admin, unvested);
+
+        Ok(unvested)
+    }
+
+    /// Get vesting grant by ID
+    pub fn get_vesting(env: Env, vesting_id: u64) -> Result<VestingGrant, VaultError> {
+        storage::get_vesting(&env, vesting_id)
+    }
+
+    /// Get the amount vested so far (claimed or not)
+    pub fn get_vested_amount(env: Env, vesting_id: u64) -> Result<i128, VaultError> {
+        let grant = storage::get_vesting(&env, vesting_id)?;
+        Ok(calculate_vested_amount(&env, &grant))
+    }
+
+    /// Get the amount already claimed by the beneficiary
+    pub fn get_claimed_amount(env: Env, vesting_id: u64) -> Result<i128, VaultError> {
+        let grant = storage::get_vesting(&env, vesting_id)?;
+        Ok(grant.claimed)
+    }
+
+    // ========================================================================
+    // Streaming Payments
+    // ========================================================================
+
+    /// Propose a payment stream accruing `rate_per_ledger` to `recipient`
+    ///
+    /// Only Treasurer or Admin can propose. Accrual starts once `config.threshold`
+    /// signers have approved it via `approve_stream` and runs for `duration` ledgers.
+    /// The stream total (`rate_per_ledger * duration`) must fit the per-proposal
+    /// spending limit. Daily/weekly limits, address caps and the vault balance
+    /// are enforced on each withdrawal rather than on the total.
+    ///
+    /// # Returns
+    /// The unique ID of the new stream.
+    pub fn propose_stream(
+        env: Env,
+        proposer: Address,
+        recipient: Address,
+        token_addr: Address,
+        rate_per_ledger: i128,
+        duration: u64,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        let config = storage::get_config(&env)?;
+
+        if !storage::has_permission(&env, &proposer, Permission::Propose) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        check_recipient_allowed(&env, &config, &recipient)?;
+        check_token_supported(&env, &token_addr)?;
+
+        if rate_per_ledger <= 0 || duration == 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        let total = rate_per_ledger
+            .checked_mul(duration as i128)
+            .ok_or(VaultError::InvalidAmount)?;
+        (env.ledger().sequence() as u64)
+            .checked_add(duration)
+            .ok_or(VaultError::InvalidAmount)?;
+
+        if total > config.spending_limit {
+            return Err(VaultError::ExceedsProposalLimit);
+        }
+
+        let id = storage::increment_stream_id(&env);
+        let stream = Stream {
+            id,
+            proposer: proposer.clone(),
+            recipient: recipient.clone(),
+            token: token_addr,
+            rate_per_ledger,
+            duration,
+            start_ledger: 0,
+            end_ledger: 0,
+            withdrawn: 0,
+            approvals: Vec::new(&env),
+            status: StreamStatus::Pending,
+        };
+
+        storage::set_stream(&env, &stream);
+        storage::add_recipient_stream(&env, &recipient, id);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_created(&env, id, &proposer, &recipient, rate_per_ledger);
+
+        Ok(id)
+    }
+
+    /// Approve a pending payment stream
+    ///
+    /// Requires a signer with Treasurer or Admin role. When `config.threshold`
+    /// approvals are reached, the stream becomes `Active` and starts accruing.
+    pub fn approve_stream(env: Env, signer: Address, stream_id: u64) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        let mut stream = storage::get_stream(&env, stream_id)?;
+        let owed_before = stream_owed(&env, &stream);
+        if stream.status != StreamStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if stream.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        stream.approvals.push_back(signer.clone());
+        let approval_count = count_signer_votes(&config, &stream.approvals);
+        if approval_count >= config.threshold {
+            let current_ledger = env.ledger().sequence() as u64;
+            stream.status = StreamStatus::Active;
+            stream.start_ledger = current_ledger;
+            stream.end_ledger = current_ledger
+                .checked_add(stream.duration)
+                .ok_or(VaultError::InvalidAmount)?;
+            events::emit_stream_started(&env, stream_id, stream.start_ledger, stream.end_ledger);
+        }
+
+        storage::add_liability(
+            &env,
+            &stream.token,
+            stream_owed(&env, &stream) - owed_before,
+        );
+        storage::set_stream(&env, &stream);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_approved(&env, stream_id, &signer, approval_count);
+
+        Ok(())
+    }
+
+    /// Withdraw accrued tokens from a stream
+    ///
+    /// Only the recipient can withdraw. Withdrawals count toward the daily and
+    /// weekly limits and the address caps; if the full accrued amount does not
+    /// fit, only the remaining headroom is paid out and the rest stays claimable.
+    ///
+    /// # Returns
+    /// The amount transferred to the recipient.
+    pub fn withdraw_from_stream(
+        env: Env,
+        recipient: Address,
+        stream_id: u64,
+    ) -> Result<i128, VaultError> {
+        recipient.require_auth();
+        record_activity(&env, &recipient);
+
+        let config = storage::get_config(&env)?;
+        let mut stream = storage::get_stream(&env, stream_id)?;
+        let owed_before = stream_owed(&env, &stream);
+        if stream.recipient != recipient {
+            return Err(VaultError::Unauthorized);
+        }
+        if stream.status != StreamStatus::Active && stream.status != StreamStatus::Cancelled {
+            return Err(VaultError::StreamNotActive);
+        }
+        if storage::is_denylisted(&env, &recipient) {
+            return Err(VaultError::RecipientDenylisted);
+        }
+
+        let accrued = calculate_streamed_amount(&env, &stream) - stream.withdrawn;
+        if accrued <= 0 {
+            return Err(VaultError::NothingToClaim);
+        }
+        check_token_supported(&env, &stream.token)?;
+
+        let amount = accrued.min(payout_headroom(
+            &env,
+            &config,
+            &stream.recipient,
+            &stream.proposer,
+            None,
+        )?);
+
+        let balance = token::balance(&env, &stream.token);
+        if balance < amount {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        token::transfer(&env, &stream.token, &recipient, amount);
+        storage::add_spent(&env, amount);
+        storage::add_address_spent(&env, &stream.recipient, &stream.proposer, amount);
+
+        stream.withdrawn += amount;
+        let current_ledger = env.ledger().sequence() as u64;
+        if current_ledger >= stream.end_ledger
+            && stream.withdrawn >= calculate_streamed_amount(&env, &stream)
+        {
+            stream.status = StreamStatus::Completed;
+        }
+        storage::add_liability(
+            &env,
+            &stream.token,
+            stream_owed(&env, &stream) - owed_before,
+        );
+        storage::set_stream(&env, &stream);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_withdrawn(&env, stream_id, &recipient, amount);
+
+        Ok(amount)
+    }
+
+    /// Cancel a stream with pro-rata settlement
+    ///
+    /// Admin or the recipient can cancel. Accrual stops at the current ledger: the
+    /// amount accrued so far stays withdrawable by the recipient, and the
+    /// unstreamed remainder stays in the treasury. Pending streams can also be
+    /// cancelled before they start.
+    ///
+    /// # Returns
+    /// The unstreamed amount retained by the treasury.
+    pub fn cancel_stream(env: Env, caller: Address, stream_id: u64) -> Result<i128, VaultError> {
+        caller.require_auth();
+        record_activity(&env, &caller);
+
+        let mut stream = storage::get_stream(&env, stream_id)?;
+
+        if !storage::has_permission(&env, &caller, Permission::ManageProposals)
+            && caller != stream.recipient
+        {
+            return Err(VaultError::Unauthorized);
+        }
+        if stream.status != StreamStatus::Pending && stream.status != StreamStatus::Active {
+            return Err(VaultError::StreamNotActive);
+        }
+
+        let (owed, refunded) = stop_stream(&env, &mut stream);
+        storage::remove_recipient_stream(&env, &stream.recipient, stream_id);
+        storage::set_stream(&env, &stream);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_stream_cancelled(&env, stream_id, &caller, owed, refunded);
+
+        Ok(refunded)
+    }
+
+    /// Get stream by ID
+    pub fn get_stream(env: Env, stream_id: u64) -> Result<Stream, VaultError> {
+        storage::get_stream(&env, stream_id)
+    }
+
+    /// Get the amount accrued by a stream and not yet withdrawn
+    pub fn get_stream_balance(env: Env, stream_id: u64) -> Result<i128, VaultError> {
+        let stream = storage::get_stream(&env, stream_id)?;
+        Ok(calculate_streamed_amount(&env, &stream) - stream.withdrawn)
+    }
+
+    // ========================================================================
+    // Social Recovery
+    // ========================================================================
+
+    /// Propose a change to the recovery or succession settings
+    ///
+    /// Requires `ManageSigners`. The change is applied once `config.threshold`
+    /// signers approve it via `approve_safeguard`.
+    ///
+    /// With `RecoveryConfig`, `threshold` recovery guardians can propose a new
+    /// signer set, which takes effect only after `delay` ledgers (at least ~3
+    /// days).
+    ///
+    /// With `SuccessionPlan`, if no signer acts for `inactivity_period` ledgers
+    /// (at least ~30 days), a successor or the beneficiary can call
+    /// `trigger_succession`. The plan needs successors, a beneficiary, or both.
+    pub fn propose_safeguard(
+        env: Env,
+        proposer: Address,
+        change: SafeguardChange,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        if !storage::has_permission(&env, &proposer, Permission::ManageSigners) {
+            return Err(VaultError::Unauthorized);
+        }
+        validate_safeguard_change(&env, &change)?;
+
+        let id = storage::increment_safeguard_id(&env);
+        let proposal = SafeguardProposal {
+            id,
+            proposer: proposer.clone(),
+            change,
+            approvals: Vec::new(&env),
+            executed: false,
+        };
+        storage::set_safeguard(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_safeguard_proposed(&env, id, &proposer);
+
+        Ok(id)
+    }
+
+    /// Approve a pending safeguard change
+    ///
+    /// Requires a signer with `Approve`. The change is applied when
+    /// `config.threshold` approvals are reached.
+    pub fn approve_safeguard(
+        env: Env,
+        signer: Address,
+        safeguard_id: u64,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        let mut proposal = storage::get_safeguard(&env, safeguard_id)?;
+        if proposal.executed {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+        if proposal.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer.clone());
+        let approval_count = count_signer_votes(&config, &proposal.approvals);
+        if approval_count >= config.threshold {
+            validate_safeguard_change(&env, &proposal.change)?;
+            match &proposal.change {
+                SafeguardChange::RecoveryConfig(recovery_config) => {
+                    storage::set_recovery_config(&env, recovery_config);
+                }
+                SafeguardChange::SuccessionPlan(plan) => {
+                    storage::set_succession_plan(&env, plan);
+                }
+            }
+            proposal.executed = true;
+        }
+
+        storage::set_safeguard(&env, &proposal);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_safeguard_approved(&env, safeguard_id, &signer, approval_count);
+
+        Ok(())
+    }
+
+    /// Get a safeguard change by ID
+    pub fn get_safeguard(env: Env, safeguard_id: u64) -> Result<SafeguardProposal, VaultError> {
+        storage::get_safeguard(&env, safeguard_id)
+    }
+
+    /// Propose replacing the signer set
+    ///
+    /// Only a recovery guardian can propose; the proposal counts as their
+    /// approval. `new_admin` must be one of `new_signers`.
+    pub fn propose_recovery(
+        env: Env,
+        recovery_guardian: Address,
+        new_signers: Vec<Address>,
+        new_threshold: u32,
+        new_admin: Address,
+    ) -> Result<u64, VaultError> {
+        recovery_guardian.require_auth();
+        record_activity(&env, &recovery_guardian);
+
+        let recovery_config = storage::get_recovery_config(&env).ok_or(VaultError::Unauthorized)?;
+        if !recovery_config
+            .recovery_guardians
+            .contains(&recovery_guardian)
+        {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if new_signers.is_empty() {
+            return Err(VaultError::NoSigners);
+        }
+        if new_threshold < 1 {
+            return Err(VaultError::ThresholdTooLow);
+        }
+        if new_threshold > new_signers.len() {
+            return Err(VaultError::ThresholdTooHigh);
+        }
+        check_signer_count(&env, new_signers.len())?;
+        check_unique(&new_signers)?;
+        validate_signer_set_change(&env, &new_signers, new_threshold)?;
+        if !new_signers.contains(&new_admin) {
+            return Err(VaultError::SignerNotFound);
+        }
+
+        let id = storage::increment_recovery_id(&env);
+        let mut recovery = RecoveryProposal {
+            id,
+            proposer: recovery_guardian.clone(),
+            new_signers: new_signers.clone(),
+            new_threshold,
+            new_admin,
+            approvals: Vec::new(&env),
+            executable_at: 0,
+            status: RecoveryStatus::Pending,
+        };
+        recovery.approvals.push_back(recovery_guardian.clone());
+        if recovery.approvals.len() >= recovery_config.threshold {
+            recovery.executable_at = env.ledger().sequence() as u64 + recovery_config.delay;
+        }
+
+        storage::set_recovery(&env, &recovery);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_recovery_proposed(&env, id, &recovery_guardian, new_signers.len());
+
+        Ok(id)
+    }
+
+    /// Approve a pending recovery
+    ///
+    /// When the recovery guardian threshold is reached the recovery delay starts.
+    pub fn approve_recovery(
+        env: Env,
+        recovery_guardian: Address,
+        recovery_id: u64,
+    ) -> Result<(), VaultError> {
+        recovery_guardian.require_auth();
+        record_activity(&env, &recovery_guardian);
+
+        let recovery_config = storage::get_recovery_config(&env).ok_or(VaultError::Unauthorized)?;
+        if !recovery_config
+            .recovery_guardians
+            .contains(&recovery_guardian)
+        {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut recovery = storage::get_recovery(&env, recovery_id)?;
+        if recovery.status != RecoveryStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if recovery.approvals.contains(&recovery_guardian) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        recovery.approvals.push_back(recovery_guardian.clone());
+        if recovery.executable_at == 0 && recovery.approvals.len() >= recovery_config.threshold {
+            recovery.executable_at = env.ledger().sequence() as u64 + recovery_config.delay;
+        }
+
+        storage::set_recovery(&env, &recovery);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_recovery_approved(
+            &env,
+            recovery_id,
+            &recovery_guardian,
+            recovery.approvals.len(),
+            recovery.executable_at,
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a pending recovery
+    ///
+    /// Any current signer can cancel at any point before execution.
+    pub fn cancel_recovery(env: Env, signer: Address, recovery_id: u64) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let mut recovery = storage::get_recovery(&env, recovery_id)?;
+        if recovery.status != RecoveryStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+
+        recovery.status = RecoveryStatus::Cancelled;
+        storage::set_recovery(&env, &recovery);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_recovery_cancelled(&env, recovery_id, &signer);
+
+        Ok(())
+    }
+
+    /// Install the recovered signer set
+    ///
+    /// Can be called by anyone once the delay has passed. Removed signers are
+    /// reset to Member, `new_admin` becomes Admin and other new signers without
+    /// voting rights become Treasurers.
+    pub fn execute_recovery(env: Env, recovery_id: u64) -> Result<(), VaultError> {
+        let mut recovery = storage::get_recovery(&env, recovery_id)?;
+        if recovery.status != RecoveryStatus::Pending {
+            return Err(VaultError::ProposalNotPending);
+        }
+        if recovery.executable_at == 0 {
+            return Err(VaultError::ProposalNotApproved);
+        }
+        if (env.ledger().sequence() as u64) < recovery.executable_at {
+            return Err(VaultError::TimelockNotExpired);
+        }
+
+        let config = replace_signer_set(
+            &env,
+            &recovery.new_signers,
+            recovery.new_threshold,
+            &recovery.new_admin,
+        )?;
+
+        recovery.status = RecoveryStatus::Executed;
+        storage::set_recovery(&env, &recovery);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_recovery_executed(&env, recovery_id, config.signers.len(), config.threshold);
+
+        Ok(())
+    }
+
+    /// Get the social recovery settings
+    pub fn get_recovery_config(env: Env) -> Option<RecoveryConfig> {
+        storage::get_recovery_config(&env)
+    }
+
+    /// Get a recovery proposal by ID
+    pub fn get_recovery(env: Env, recovery_id: u64) -> Result<RecoveryProposal, VaultError> {
+        storage::get_recovery(&env, recovery_id)
+    }
+
+    // ========================================================================
+    // Succession
+    // ========================================================================
+
+    /// Trigger succession after signer inactivity
+    ///
+    /// Callable by a registered successor or the beneficiary once no signer has
+    /// acted for the plan's inactivity period. Successors replace the signer set
+    /// (the first becomes Admin), and the balance of each of `tokens` not owed to
+    /// active vesting grants or streams is swept to the beneficiary. The
+    /// beneficiary must pass the denylist and allowlist checks and each token the
+    /// registry check. The plan is consumed.
+    pub fn trigger_succession(
+        env: Env,
+        caller: Address,
+        tokens: Vec<Address>,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+
+        let plan = storage::get_succession_plan(&env).ok_or(VaultError::Unauthorized)?;
+        if !plan.successors.contains(&caller) && plan.beneficiary.as_ref() != Some(&caller) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let last_activity = storage::get_last_activity(&env);
+        if (env.ledger().sequence() as u64) < last_activity.saturating_add(plan.inactivity_period) {
+            return Err(VaultError::TimelockNotExpired);
+        }
+
+        if let Some(beneficiary) = &plan.beneficiary {
+            let config = storage::get_config(&env)?;
+            check_recipient_allowed(&env, &config, beneficiary)?;
+            for token_addr in tokens.iter() {
+                check_token_supported(&env, &token_addr)?;
+            }
+        }
+
+        if let Some(new_admin) = plan.successors.first() {
+            replace_signer_set(&env, &plan.successors, plan.threshold, &new_admin)?;
+        }
+
+        let mut swept = 0;
+        if let Some(beneficiary) = &plan.beneficiary {
+            for token_addr in tokens.iter() {
+                let sweepable =
+                    token::balance(&env, &token_addr) - storage::get_liability(&env, &token_addr);
+                if sweepable > 0 {
+                    token::transfer(&env, &token_addr, beneficiary, sweepable);
+                    swept += 1;
+                }
+            }
+        }
+
+        storage::remove_succession_plan(&env);
+        storage::set_last_activity(&env, env.ledger().sequence() as u64);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_succession_triggered(
+            &env,
+            &caller,
+            last_activity,
+            plan.successors.len(),
+            swept,
+        );
+
+        Ok(())
+    }
+
+    /// Record a liveness heartbeat for a signer
+    pub fn heartbeat(env: Env, signer: Address) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        let ledger = env.ledger().sequence() as u64;
+        let mut activity = storage::get_signer_activity(&env, &signer);
+        activity.last_heartbeat = ledger;
+        storage::set_signer_activity(&env, &signer, &activity);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_heartbeat(&env, &signer, ledger);
+
+        Ok(())
+    }
+
+    /// Get a signer's last vote and heartbeat ledgers
+    pub fn get_signer_activity(env: Env, signer: Address) -> SignerActivity {
+        storage::get_signer_activity(&env, &signer)
+    }
+
+    /// List signers that have neither voted nor sent a heartbeat within
+    /// `max_idle` ledgers
+    pub fn get_inactive_signers(env: Env, max_idle: u64) -> Result<Vec<Address>, VaultError> {
+        let config = storage::get_config(&env)?;
+        let mut inactive = Vec::new(&env);
+        for signer in config.signers.iter() {
+            if !is_active_signer(&env, &signer, max_idle) {
+                inactive.push_back(signer);
+            }
+        }
+        Ok(inactive)
+    }
+
+    /// Set the window used to compute `Percentage` thresholds over active signers
+    ///
+    /// Requires `ManageSigners`. With a non-zero window, only signers that voted
+    /// or sent a heartbeat within that many ledgers count towards the
+    /// percentage. 0 disables the filter.
+    pub fn set_active_signer_window(
+        env: Env,
+        admin: Address,
+        window: u64,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::set_active_signer_window(&env, window);
+        storage::extend_instance_ttl(&env);
 
-        let payment = crate::types::RecurringPayment {
-            id,
-            proposer: proposer.clone(),
-            recipient,
-            token: token_addr,
-            amount,
-            memo,
-            interval,
-            next_payment_ledger: current_ledger + interval,
-            payment_count: 0,
-            is_active: true,
+        Ok(())
+    }
+
+    /// Get the active-signer window (0 if disabled)
+    pub fn get_active_signer_window(env: Env) -> u64 {
+        storage::get_active_signer_window(&env)
+    }
+
+    /// Set the maximum number of signers
+    ///
+    /// Requires `ManageSigners`. Cannot be set below the current signer count.
+    pub fn set_max_signers(env: Env, admin: Address, max: u32) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageSigners) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let config = storage::get_config(&env)?;
+        if max < config.signers.len() {
+            return Err(VaultError::TooManySigners);
+        }
+
+        storage::set_max_signers(&env, max);
+        storage::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the maximum number of signers
+    pub fn get_max_signers(env: Env) -> u32 {
+        storage::get_max_signers(&env)
+    }
+
+    /// Get the registered succession plan
+    pub fn get_succession_plan(env: Env) -> Option<SuccessionPlan> {
+        storage::get_succession_plan(&env)
+    }
+
+    /// Get the ledger of the most recent signer action
+    pub fn get_last_activity(env: Env) -> u64 {
+        storage::get_last_activity(&env)
+    }
+
+    // ========================================================================
+    // Token Registry
+    // ========================================================================
+
+    /// Add a token to the supported token registry
+    ///
+    /// Only Admin can register. Symbol and decimals are read from the token
+    /// contract. Once the registry is enforced, every transfer path rejects
+    /// tokens outside it.
+    pub fn register_token(
+        env: Env,
+        admin: Address,
+        token_addr: Address,
+    ) -> Result<TokenInfo, VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageTokens) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        if storage::get_token_info(&env, &token_addr).is_some() {
+            return Err(VaultError::TokenAlreadyRegistered);
+        }
+
+        let info = TokenInfo {
+            address: token_addr.clone(),
+            symbol: token::symbol(&env, &token_addr),
+            decimals: token::decimals(&env, &token_addr),
+            enabled: true,
+            added_at: env.ledger().sequence() as u64,
         };
+        storage::set_token_info(&env, &info);
+        storage::extend_instance_ttl(&env);
 
-        storage::set_recurring_payment(&env, &payment);
+        events::emit_token_registered(&env, &token_addr, &admin, info.decimals);
 
-        // Use a generic event or add a specific one (skipping specific event for brevity/limit)
+        Ok(info)
+    }
 
-        Ok(id)
+    /// Enable or disable a registered token
+    ///
+    /// Only Admin can toggle. Disabled tokens are rejected on every transfer path.
+    pub fn set_token_enabled(
+        env: Env,
+        admin: Address,
+        token_addr: Address,
+        enabled: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageTokens) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut info =
+            storage::get_token_info(&env, &token_addr).ok_or(VaultError::TokenNotSupported)?;
+        info.enabled = enabled;
+        storage::set_token_info(&env, &info);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_token_status_changed(&env, &token_addr, &admin, enabled);
+
+        Ok(())
     }
 
-    /// Execute a scheduled recurring payment
+    /// Remove a token from the registry
     ///
-    /// Can be called by anyone (keeper/bot) if the schedule is due.
-    pub fn execute_recurring_payment(env: Env, payment_id: u64) -> Result<(), VaultError> {
-        let mut payment = storage::get_recurring_payment(&env, payment_id)?;
+    /// Only Admin can remove. While the registry is enforced, the token is
+    /// rejected on every transfer path afterwards.
+    pub fn remove_token(env: Env, admin: Address, token_addr: Address) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
 
-        if !payment.is_active {
-            return Err(VaultError::ProposalNotFound); // Or specific "NotActive" error
+        if !storage::has_permission(&env, &admin, Permission::ManageTokens) {
+            return Err(VaultError::Unauthorized);
         }
 
-        let current_ledger = env.ledger().sequence() as u64;
-        if current_ledger < payment.next_payment_ledger {
-            return Err(VaultError::TimelockNotExpired); // Reuse error for "Too Early"
+        if storage::get_token_info(&env, &token_addr).is_none() {
+            return Err(VaultError::TokenNotSupported);
+        }
+        storage::remove_token_info(&env, &token_addr);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_token_removed(&env, &token_addr, &admin);
+
+        Ok(())
+    }
+
+    /// Restrict (or stop restricting) transfers to registered tokens
+    ///
+    /// Only Admin can switch. Enforcement requires at least one registered
+    /// token, so a vault cannot lock itself out of every transfer path.
+    pub fn set_token_registry_enforced(
+        env: Env,
+        admin: Address,
+        enforced: bool,
+    ) -> Result<(), VaultError> {
+        admin.require_auth();
+        record_activity(&env, &admin);
+
+        if !storage::has_permission(&env, &admin, Permission::ManageTokens) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        let mut registry = storage::get_token_registry(&env);
+        if enforced && registry.tokens.is_empty() {
+            return Err(VaultError::TokenNotSupported);
+        }
+        registry.enforced = enforced;
+        storage::set_token_registry(&env, &registry);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_token_registry_enforced(&env, &admin, enforced);
+
+        Ok(())
+    }
+
+    /// Check whether transfers are restricted to registered tokens
+    pub fn is_token_registry_enforced(env: Env) -> bool {
+        storage::get_token_registry(&env).enforced
+    }
+
+    /// Get registry metadata for a token
+    pub fn get_token_info(env: Env, token_addr: Address) -> Result<TokenInfo, VaultError> {
+        storage::get_token_info(&env, &token_addr).ok_or(VaultError::TokenNotSupported)
+    }
+
+    /// List all registered tokens
+    pub fn list_tokens(env: Env) -> Vec<TokenInfo> {
+        let mut tokens = Vec::new(&env);
+        for addr in storage::get_token_registry(&env).tokens.iter() {
+            if let Some(info) = storage::get_token_info(&env, &addr) {
+                tokens.push_back(info);
+            }
+        }
+        tokens
+    }
+
+    // ========================================================================
+    // Denylist
+    // ========================================================================
+
+    /// Add an address to the recipient denylist
+    ///
+    /// Only Admin or Guardian can denylist. Active recurring payments and
+    /// streams to the address are halted and its open proposals are flagged;
+    /// transfers to it are rejected at proposal, execution, recurring payment,
+    /// vesting claim and stream withdrawal time. Halted schedules and expired
+    /// proposals are dropped from the recipient's indexes.
+    pub fn denylist_address(env: Env, caller: Address, addr: Address) -> Result<(), VaultError> {
+        caller.require_auth();
+        record_activity(&env, &caller);
+
+        if !storage::has_permission(&env, &caller, Permission::ManageDenylist) {
+            return Err(VaultError::Unauthorized);
+        }
+
+        storage::add_to_denylist(&env, &addr);
+
+        // Halt active recurring payments to the address
+        let mut halted = 0;
+        for payment_id in storage::get_recipient_recurring(&env, &addr).iter() {
+            if let Ok(mut payment) = storage::get_recurring_payment(&env, payment_id) {
+                if payment.is_active {
+                    payment.is_active = false;
+                    storage::set_recurring_payment(&env, &payment);
+                    halted += 1;
+                }
+            }
+            // Halted payments stay halted, so they no longer need indexing
+            storage::remove_recipient_recurring(&env, &addr, payment_id);
+        }
+
+        // Stop accrual on pending and active streams to the address
+        for stream_id in storage::get_recipient_streams(&env, &addr).iter() {
+            if let Ok(mut stream) = storage::get_stream(&env, stream_id) {
+                if stream.status == StreamStatus::Pending || stream.status == StreamStatus::Active {
+                    let (owed, refunded) = stop_stream(&env, &mut stream);
+                    storage::set_stream(&env, &stream);
+                    events::emit_stream_cancelled(&env, stream_id, &caller, owed, refunded);
+                    halted += 1;
+                }
+            }
+            storage::remove_recipient_stream(&env, &addr, stream_id);
         }
 
-        // Check spending limits (Daily & Weekly)
-        // Note: Recurring payments count towards limits!
+        // Flag open proposals targeting the address
         let config = storage::get_config(&env)?;
+        let mut flagged = 0;
+        for proposal_id in storage::get_recipient_proposals(&env, &addr).iter() {
+            let Ok(mut proposal) = storage::get_proposal(&env, proposal_id) else {
+                storage::remove_recipient_proposal(&env, &addr, proposal_id);
+                continue;
+            };
+            let open = (proposal.status == ProposalStatus::Pending
+                || proposal.status == ProposalStatus::Approved)
+                && !is_expired(&env, &config, &proposal);
+            if !open {
+                storage::remove_recipient_proposal(&env, &addr, proposal_id);
+            } else if !proposal.flagged {
+                proposal.flagged = true;
+                storage::set_proposal(&env, &proposal);
+                flagged += 1;
+            }
+        }
+        storage::extend_instance_ttl(&env);
 
-        let today = storage::get_day_number(&env);
-        let spent_today = storage::get_daily_spent(&env, today);
-        if spent_today + payment.amount > config.daily_limit {
-            return Err(VaultError::ExceedsDailyLimit);
+        events::emit_address_denylisted(&env, &addr, &caller, halted, flagged);
+
+        Ok(())
+    }
+
+    /// Remove an address from the recipient denylist
+    ///
+    /// Only Admin or Guardian can remove. Halted recurring payments and streams
+    /// stay halted.
+    pub fn remove_from_denylist(
+        env: Env,
+        caller: Address,
+        addr: Address,
+    ) -> Result<(), VaultError> {
+        caller.require_auth();
+        record_activity(&env, &caller);
+
+        if !storage::has_permission(&env, &caller, Permission::ManageDenylist) {
+            return Err(VaultError::Unauthorized);
         }
 
-        let week = storage::get_week_number(&env);
-        let spent_week = storage::get_weekly_spent(&env, week);
-        if spent_week + payment.amount > config.weekly_limit {
-            return Err(VaultError::ExceedsWeeklyLimit);
+        storage::remove_from_denylist(&env, &addr);
+        storage::extend_instance_ttl(&env);
+
+        events::emit_address_undenylisted(&env, &addr, &caller);
+
+        Ok(())
+    }
+
+    /// Check if an address is denylisted
+    pub fn is_denylisted(env: Env, addr: Address) -> bool {
+        storage::is_denylisted(&env, &addr)
+    }
+
+    /// List all denylisted addresses
+    pub fn get_denylist(env: Env) -> Vec<Address> {
+        storage::get_denylist(&env)
+    }
+
+    // ========================================================================
+    // Address Book
+    // ========================================================================
+
+    /// Propose adding (or relabelling) or removing an address book entry, or
+    /// toggling allowlist-only mode
+    ///
+    /// Only Treasurer or Admin can propose. The change takes effect once
+    /// `config.threshold` signers have approved it via `approve_address_book`.
+    /// `addr` and `label` are ignored by the allowlist toggles.
+    ///
+    /// # Returns
+    /// The unique ID of the address book proposal.
+    pub fn propose_address_book(
+        env: Env,
+        proposer: Address,
+        action: AddressBookAction,
+        addr: Address,
+        label: Symbol,
+    ) -> Result<u64, VaultError> {
+        proposer.require_auth();
+        record_activity(&env, &proposer);
+
+        storage::get_config(&env)?;
+
+        if !storage::has_permission(&env, &proposer, Permission::Propose) {
+            return Err(VaultError::InsufficientRole);
         }
 
-        // Check balance
-        let balance = token::balance(&env, &payment.token);
-        if balance < payment.amount {
-            return Err(VaultError::InsufficientBalance);
+        if action == AddressBookAction::Remove
+            && storage::get_address_book_entry(&env, &addr).is_none()
+        {
+            return Err(VaultError::AddressNotInBook);
         }
 
-        // Execute
-        token::transfer(&env, &payment.token, &payment.recipient, payment.amount);
+        let id = storage::increment_address_book_proposal_id(&env);
+        let proposal = AddressBookProposal {
+            id,
+            proposer: proposer.clone(),
+            action: action.clone(),
+            address: addr.clone(),
+            label,
+            approvals: Vec::new(&env),
+            executed: false,
+        };
+
+        storage::set_address_book_proposal(&env, &proposal);
+        storage::extend_instance_ttl(&env);
 
-        // Update limits
-        storage::add_daily_spent(&env, today, payment.amount);
-        storage::add_weekly_spent(&env, week, payment.amount);
+        events::emit_address_book_proposed(&env, id, &proposer, &addr, action as u32);
 
-        // Update payment schedule
-        payment.next_payment_ledger += payment.interval;
-        payment.payment_count += 1;
-        storage::set_recurring_payment(&env, &payment);
+        Ok(id)
+    }
+
+    /// Approve a pending address book change
+    ///
+    /// Requires a signer with Treasurer or Admin role. The change is applied when
+    /// `config.threshold` approvals are reached.
+    pub fn approve_address_book(
+        env: Env,
+        signer: Address,
+        proposal_id: u64,
+    ) -> Result<(), VaultError> {
+        signer.require_auth();
+        record_activity(&env, &signer);
+
+        let config = storage::get_config(&env)?;
+        if !config.signers.contains(&signer) {
+            return Err(VaultError::NotASigner);
+        }
+
+        if !storage::has_permission(&env, &signer, Permission::Approve) {
+            return Err(VaultError::InsufficientRole);
+        }
+
+        let mut proposal = storage::get_address_book_proposal(&env, proposal_id)?;
+        if proposal.executed {
+            return Err(VaultError::ProposalAlreadyExecuted);
+        }
+        if proposal.approvals.contains(&signer) {
+            return Err(VaultError::AlreadyApproved);
+        }
+
+        proposal.approvals.push_back(signer.clone());
+        let approval_count = count_signer_votes(&config, &proposal.approvals);
+        if approval_count >= config.threshold {
+            match proposal.action {
+                AddressBookAction::Add => {
+                    let entry = AddressBookEntry {
+                        address: proposal.address.clone(),
+                        label: proposal.label.clone(),
+                        added_by: proposal.proposer.clone(),
+                        added_at: env.ledger().sequence() as u64,
+                    };
+                    storage::set_address_book_entry(&env, &entry);
+                }
+                AddressBookAction::Remove => {
+                    if storage::get_address_book_entry(&env, &proposal.address).is_none() {
+                        return Err(VaultError::AddressNotInBook);
+                    }
+                    storage::remove_address_book_entry(&env, &proposal.address);
+                }
+                AddressBookAction::EnableAllowlist | AddressBookAction::DisableAllowlist => {
+                    let mut new_config = config.clone();
+                    new_config.allowlist_only =
+                        proposal.action == AddressBookAction::EnableAllowlist;
+                    storage::set_config(&env, &new_config);
+                    events::emit_config_updated(&env, &signer, &config, &new_config);
+                }
+            }
+            proposal.executed = true;
+            events::emit_address_book_updated(
+                &env,
+                &proposal.address,
+                &proposal.label,
+                proposal.action.clone() as u32,
+            );
+        }
+
+        storage::set_address_book_proposal(&env, &proposal);
         storage::extend_instance_ttl(&env);
 
+        events::emit_address_book_approved(&env, proposal_id, &signer, approval_count);
+
         Ok(())
     }
 
+    /// Get an address book entry
+    pub fn get_address_book_entry(env: Env, addr: Address) -> Result<AddressBookEntry, VaultError> {
+        storage::get_address_book_entry(&env, &addr).ok_or(VaultError::AddressNotInBook)
+    }
+
+    /// List all address book entries
+    pub fn list_address_book(env: Env) -> Vec<AddressBookEntry> {
+        let mut entries = Vec::new(&env);
+        for addr in storage::get_address_book_index(&env).iter() {
+            if let Some(entry) = storage::get_address_book_entry(&env, &addr) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    /// Get an address book proposal by ID
+    pub fn get_address_book_proposal(
+        env: Env,
+        proposal_id: u64,
+    ) -> Result<AddressBookProposal, VaultError> {
+        storage::get_address_book_proposal(&env, proposal_id)
+    }
+
     /// Get proposal by ID
     pub fn get_proposal(env: Env, proposal_id: u64) -> Result<Proposal, VaultError> {
         storage::get_proposal(&env, proposal_id)
@@ -844,6 +3682,8 @@ impl VaultDAO {
     }
 
     /// Change priority of a proposal (Admin only)
+    ///
+    /// Only open (`Pending` or `Approved`, unexpired) proposals can be moved.
     pub fn change_priority(
         env: Env,
         admin: Address,
@@ -851,19 +3691,22 @@ impl VaultDAO {
         new_priority: Priority,
     ) -> Result<(), VaultError> {
         admin.require_auth();
+        record_activity(&env, &admin);
 
-        let role = storage::get_role(&env, &admin);
-        if role != Role::Admin {
+        if !storage::has_permission(&env, &admin, Permission::ManageProposals) {
             return Err(VaultError::Unauthorized);
         }
 
         let mut proposal = storage::get_proposal(&env, proposal_id)?;
 
-        if proposal.status == ProposalStatus::Executed
-            || proposal.status == ProposalStatus::Rejected
+        if proposal.status != ProposalStatus::Pending && proposal.status != ProposalStatus::Approved
         {
             return Err(VaultError::ProposalNotPending);
         }
+        let config = storage::get_config(&env)?;
+        if is_expired(&env, &config, &proposal) {
+            return Err(VaultError::ProposalExpired);
+        }
 
         let old_priority = proposal.priority.clone();
         storage::remove_from_priority_queue(&env, old_priority as u32, proposal_id);
@@ -886,10 +3729,85 @@ impl VaultDAO {
         storage::get_daily_spent(&env, day)
     }
 
-    /// Get today's spending
-    pub fn get_today_spent(env: Env) -> i128 {
-        let today = storage::get_day_number(&env);
-        storage::get_daily_spent(&env, today)
+    /// Get spending counted against the daily or weekly limit
+    ///
+    /// Uses calendar buckets or rolling windows depending on `limit_window_mode`.
+    pub fn get_window_spent(env: Env, window: SpendWindow) -> Result<i128, VaultError> {
+        let config = storage::get_config(&env)?;
+        Ok(window_spent(&env, &config, window))
+    }
+
+    /// Get the per-address rolling caps
+    pub fn get_address_caps(env: Env) -> AddressCaps {
+        storage::get_address_caps(&env)
+    }
+
+    /// Get how much more a recipient can receive in the current 24h window
+    ///
+    /// Returns `i128::MAX` if recipients are uncapped.
+    pub fn get_recipient_headroom(env: Env, recipient: Address) -> i128 {
+        let caps = storage::get_address_caps(&env);
+        if caps.recipient_cap > 0 {
+            caps.recipient_cap - storage::get_recipient_spent(&env, &recipient)
+        } else {
+            i128::MAX
+        }
+    }
+
+    /// Get how much more a proposer can move in the current 24h window
+    ///
+    /// Returns `i128::MAX` if proposers are uncapped.
+    pub fn get_proposer_headroom(env: Env, proposer: Address) -> i128 {
+        let caps = storage::get_address_caps(&env);
+        if caps.proposer_cap > 0 {
+            caps.proposer_cap - storage::get_proposer_spent(&env, &proposer)
+        } else {
+            i128::MAX
+        }
+    }
+
+    /// Get budget caps for a category
+    pub fn get_budget(env: Env, category: Symbol) -> Result<BudgetCategory, VaultError> {
+        storage::get_budget(&env, &category).ok_or(VaultError::UnknownBudgetCategory)
+    }
+
+    /// List all registered budget categories
+    pub fn get_budget_categories(env: Env) -> Vec<Symbol> {
+        storage::get_budget_categories(&env)
+    }
+
+    /// Get the remaining budget of a category for the current month or quarter
+    ///
+    /// Returns `i128::MAX` if the category is uncapped for that period.
+    pub fn get_budget_remaining(
+        env: Env,
+        category: Symbol,
+        period: BudgetPeriod,
+    ) -> Result<i128, VaultError> {
+        let budget =
+            storage::get_budget(&env, &category).ok_or(VaultError::UnknownBudgetCategory)?;
+        Ok(category_budget_remaining(&env, &budget, period).unwrap_or(i128::MAX))
+    }
+
+    /// Get the current vault configuration
+    pub fn get_config(env: Env) -> Result<Config, VaultError> {
+        storage::get_config(&env)
+    }
+
+    /// List signers who cannot vote on proposals
+    ///
+    /// A signer needs the `Approve` permission (e.g. Treasurer or Admin role)
+    /// for its approvals to count; signers returned here are silently unable to
+    /// vote.
+    pub fn get_non_voting_signers(env: Env) -> Result<Vec<Address>, VaultError> {
+        let config = storage::get_config(&env)?;
+        let mut result = Vec::new(&env);
+        for signer in config.signers.iter() {
+            if !storage::has_permission(&env, &signer, Permission::Approve) {
+                result.push_back(signer);
+            }
+        }
+        Ok(result)
     }
 
     /// Check if an address is a signer
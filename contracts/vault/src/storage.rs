@@ -2,12 +2,17 @@
 //!
 //! Storage keys and helper functions for persistent state.
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, Env, Symbol};
 
 use crate::errors::VaultError;
-use crate::types::{Config, Proposal, Role};
+use crate::types::{
+    AddressBookEntry, AddressBookProposal, AddressCaps, AdminGrant, BudgetCategory, Config,
+    Permission, Proposal, RecoveryConfig, RecoveryProposal, Role, RoleChange, SafeguardProposal,
+    SignerActivity, SpendRing, Stream, SuccessionPlan, TimelockTier, TokenInfo, TokenRegistry,
+    VestingGrant,
+};
 
-/// Storage key definitions
+/// Core storage keys: configuration, proposals and spending limits
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
@@ -15,8 +20,6 @@ pub enum DataKey {
     Initialized,
     /// Vault configuration -> Config
     Config,
-    /// Role assignment for address -> Role
-    Role(Address),
     /// Proposal by ID -> Proposal
     Proposal(u64),
     /// Next proposal ID counter -> u64
@@ -27,10 +30,138 @@ pub enum DataKey {
     DailySpent(u64),
     /// Weekly spending tracker (week number) -> i128
     WeeklySpent(u64),
+    /// Hourly spending ring buffer for rolling windows -> SpendRing
+    SpendRing,
+    /// Per-address rolling caps -> AddressCaps
+    AddressCaps,
+    /// Hourly amounts received by a recipient over the last 24h -> SpendRing
+    RecipientSpent(Address),
+    /// Hourly amounts proposed by a proposer over the last 24h -> SpendRing
+    ProposerSpent(Address),
     /// Recurring payment configuration -> RecurringPayment
     Recurring(u64),
     /// Next recurring payment ID counter -> u64
     NextRecurringId,
+    /// Recurring payment IDs reserving spending limit headroom -> Vec<u64>
+    LimitReservations,
+    /// Recurring payment IDs by recipient -> Vec<u64>
+    RecipientRecurring(Address),
+    /// Open proposal IDs by recipient -> Vec<u64>
+    RecipientProposals(Address),
+    /// Pending or active stream IDs by recipient -> Vec<u64>
+    RecipientStreams(Address),
+    /// Budget caps by category -> BudgetCategory
+    Budget(Symbol),
+    /// Registered budget categories -> Vec<Symbol>
+    BudgetCategories,
+    /// Category spending per month (category, month number) -> i128
+    MonthlySpent(Symbol, u64),
+    /// Category spending per quarter (category, quarter number) -> i128
+    QuarterlySpent(Symbol, u64),
+    /// Amount-based timelock tiers -> Vec<TimelockTier>
+    TimelockTiers,
+    /// Vetoes required to cancel a timelocked proposal -> u32
+    VetoThreshold,
+}
+
+/// Role and permission storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum RoleKey {
+    /// Role assignment for address -> Role
+    Role(Address),
+    /// Permission override for address -> u32 mask
+    Permissions(Address),
+    /// Ledger after which a role lapses to Member -> u64
+    RoleExpiry(Address),
+    /// Addresses with a permission override -> Vec<Address>
+    PermissionOverrides,
+    /// Number of addresses holding the Admin role -> u32
+    AdminCount,
+    /// Addresses explicitly assigned a role (Role as u32) -> Vec<Address>
+    RoleMembers(u32),
+    /// Role change history for address -> Vec<RoleChange>
+    RoleHistory(Address),
+    /// Admin grant by ID -> AdminGrant
+    AdminGrant(u64),
+    /// Next admin grant ID counter -> u64
+    NextAdminGrantId,
+}
+
+/// Signer liveness and signer set limit storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum SignerKey {
+    /// Per-signer liveness record -> SignerActivity
+    SignerActivity(Address),
+    /// Ledgers within which a signer counts as active (0 = disabled) -> u64
+    ActiveSignerWindow,
+    /// Maximum number of signers -> u32
+    MaxSigners,
+    /// Ledger of the most recent signer action -> u64
+    LastSignerActivity,
+}
+
+/// Social recovery and succession storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum RecoveryKey {
+    /// Recovery or succession settings change by ID -> SafeguardProposal
+    Safeguard(u64),
+    /// Next safeguard change ID counter -> u64
+    NextSafeguardId,
+    /// Social recovery settings -> RecoveryConfig
+    RecoveryConfig,
+    /// Recovery proposal by ID -> RecoveryProposal
+    Recovery(u64),
+    /// Next recovery proposal ID counter -> u64
+    NextRecoveryId,
+    /// Inactivity-triggered succession plan -> SuccessionPlan
+    SuccessionPlan,
+}
+
+/// Vesting grant and payment stream storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum ScheduleKey {
+    /// Vesting grant by ID -> VestingGrant
+    Vesting(u64),
+    /// Next vesting grant ID counter -> u64
+    NextVestingId,
+    /// Payment stream by ID -> Stream
+    Stream(u64),
+    /// Next stream ID counter -> u64
+    NextStreamId,
+    /// Amount still owed by active vesting grants and streams per token -> i128
+    Liability(Address),
+}
+
+/// Address book and denylist storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum AddressKey {
+    /// Address book entry -> AddressBookEntry
+    AddressBook(Address),
+    /// Addresses in the address book -> Vec<Address>
+    AddressBookIndex,
+    /// Pending address book change by ID -> AddressBookProposal
+    AddressBookProposal(u64),
+    /// Next address book proposal ID counter -> u64
+    NextAddressBookProposalId,
+    /// Denylist flag for address -> bool
+    Denylisted(Address),
+    /// Denylisted addresses -> Vec<Address>
+    DenylistIndex,
+}
+
+/// Token registry storage keys
+#[contracttype]
+#[derive(Clone)]
+pub enum TokenKey {
+    /// Supported token metadata -> TokenInfo
+    Token(Address),
+    /// Registered token addresses and enforcement flag -> TokenRegistry
+    TokenRegistry,
 }
 
 /// TTL constants (in ledgers, ~5 seconds each)
@@ -40,6 +171,7 @@ pub const INSTANCE_TTL: u32 = DAY_IN_LEDGERS * 30; // 30 days
 pub const INSTANCE_TTL_THRESHOLD: u32 = DAY_IN_LEDGERS * 7; // Extend when below 7 days
 pub const PERSISTENT_TTL: u32 = DAY_IN_LEDGERS * 30; // 30 days
 pub const PERSISTENT_TTL_THRESHOLD: u32 = DAY_IN_LEDGERS * 7; // Extend when below 7 days
+pub const DEFAULT_MAX_SIGNERS: u32 = 20;
 
 // ============================================================================
 // Initialization
@@ -72,21 +204,356 @@ pub fn set_config(env: &Env, config: &Config) {
 // Roles
 // ============================================================================
 
+/// Effective role: the assigned role, or `Member` once a time-limited grant
+/// has lapsed.
 pub fn get_role(env: &Env, addr: &Address) -> Role {
+    if role_lapsed(env, addr) {
+        return Role::Member;
+    }
+    get_assigned_role(env, addr).unwrap_or(Role::Member)
+}
+
+/// Role as stored, ignoring expiry (None if never assigned)
+pub fn get_assigned_role(env: &Env, addr: &Address) -> Option<Role> {
+    env.storage().persistent().get(&RoleKey::Role(addr.clone()))
+}
+
+pub fn get_role_expiry(env: &Env, addr: &Address) -> Option<u64> {
     env.storage()
         .persistent()
-        .get(&DataKey::Role(addr.clone()))
-        .unwrap_or(Role::Member)
+        .get(&RoleKey::RoleExpiry(addr.clone()))
+}
+
+pub fn set_role_expiry(env: &Env, addr: &Address, expires_at: Option<u64>) {
+    let key = RoleKey::RoleExpiry(addr.clone());
+    match expires_at {
+        Some(ledger) => {
+            env.storage().persistent().set(&key, &ledger);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Whether a time-limited role grant has passed its expiry ledger
+pub fn role_lapsed(env: &Env, addr: &Address) -> bool {
+    match get_role_expiry(env, addr) {
+        Some(expires_at) => env.ledger().sequence() as u64 > expires_at,
+        None => false,
+    }
 }
 
 pub fn set_role(env: &Env, addr: &Address, role: Role) {
-    let key = DataKey::Role(addr.clone());
+    let key = RoleKey::Role(addr.clone());
     env.storage().persistent().set(&key, &role);
     env.storage()
         .persistent()
         .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
 }
 
+/// Effective permission mask: the per-address override if set, otherwise the
+/// role preset.
+pub fn get_permissions(env: &Env, addr: &Address) -> u32 {
+    if role_lapsed(env, addr) {
+        return Role::Member.permissions();
+    }
+    env.storage()
+        .persistent()
+        .get(&RoleKey::Permissions(addr.clone()))
+        .unwrap_or_else(|| get_role(env, addr).permissions())
+}
+
+/// Per-address permission override, if one is set
+pub fn get_permission_override(env: &Env, addr: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::Permissions(addr.clone()))
+}
+
+pub fn set_permissions(env: &Env, addr: &Address, permissions: u32) {
+    let key = RoleKey::Permissions(addr.clone());
+    env.storage().persistent().set(&key, &permissions);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+
+    let mut overrides = get_permission_overrides(env);
+    if !overrides.contains(addr) {
+        overrides.push_back(addr.clone());
+        set_permission_overrides(env, &overrides);
+    }
+}
+
+/// Addresses that currently have a permission override
+pub fn get_permission_overrides(env: &Env) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::PermissionOverrides)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn set_permission_overrides(env: &Env, overrides: &soroban_sdk::Vec<Address>) {
+    let key = RoleKey::PermissionOverrides;
+    env.storage().persistent().set(&key, overrides);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+pub fn clear_permissions(env: &Env, addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&RoleKey::Permissions(addr.clone()));
+
+    let mut overrides = get_permission_overrides(env);
+    if let Some(idx) = overrides.first_index_of(addr) {
+        overrides.remove(idx);
+        set_permission_overrides(env, &overrides);
+    }
+}
+
+pub fn has_permission(env: &Env, addr: &Address, permission: Permission) -> bool {
+    get_permissions(env, addr) & permission as u32 != 0
+}
+
+pub fn get_role_members(env: &Env, role: u32) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::RoleMembers(role))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn set_role_members(env: &Env, role: u32, members: &soroban_sdk::Vec<Address>) {
+    let key = RoleKey::RoleMembers(role);
+    env.storage().persistent().set(&key, members);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+/// Move an address from one role's membership list to another's.
+pub fn move_role_member(env: &Env, addr: &Address, from: Option<u32>, to: u32) {
+    if let Some(from) = from {
+        let mut members = get_role_members(env, from);
+        if let Some(idx) = members.iter().position(|a| a == *addr) {
+            members.remove(idx as u32);
+            set_role_members(env, from, &members);
+        }
+    }
+    let mut members = get_role_members(env, to);
+    if !members.contains(addr) {
+        members.push_back(addr.clone());
+        set_role_members(env, to, &members);
+    }
+}
+
+pub fn get_role_history(env: &Env, addr: &Address) -> soroban_sdk::Vec<RoleChange> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::RoleHistory(addr.clone()))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+pub fn add_role_change(env: &Env, addr: &Address, change: RoleChange) {
+    let key = RoleKey::RoleHistory(addr.clone());
+    let mut history = get_role_history(env, addr);
+    history.push_back(change);
+    env.storage().persistent().set(&key, &history);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_admin_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RoleKey::AdminCount)
+        .unwrap_or(0)
+}
+
+pub fn set_admin_count(env: &Env, count: u32) {
+    env.storage().instance().set(&RoleKey::AdminCount, &count);
+}
+
+pub fn increment_admin_grant_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&RoleKey::NextAdminGrantId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&RoleKey::NextAdminGrantId, &(id + 1));
+    id
+}
+
+pub fn set_admin_grant(env: &Env, grant: &AdminGrant) {
+    let key = RoleKey::AdminGrant(grant.id);
+    env.storage().persistent().set(&key, grant);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_admin_grant(env: &Env, id: u64) -> Result<AdminGrant, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&RoleKey::AdminGrant(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+// ============================================================================
+// Safeguard Changes
+// ============================================================================
+
+pub fn increment_safeguard_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&RecoveryKey::NextSafeguardId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::NextSafeguardId, &(id + 1));
+    id
+}
+
+pub fn set_safeguard(env: &Env, proposal: &SafeguardProposal) {
+    let key = RecoveryKey::Safeguard(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_safeguard(env: &Env, id: u64) -> Result<SafeguardProposal, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&RecoveryKey::Safeguard(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+// ============================================================================
+// Social Recovery
+// ============================================================================
+
+pub fn get_recovery_config(env: &Env) -> Option<RecoveryConfig> {
+    env.storage().instance().get(&RecoveryKey::RecoveryConfig)
+}
+
+pub fn set_recovery_config(env: &Env, config: &RecoveryConfig) {
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::RecoveryConfig, config);
+}
+
+pub fn increment_recovery_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&RecoveryKey::NextRecoveryId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::NextRecoveryId, &(id + 1));
+    id
+}
+
+pub fn set_recovery(env: &Env, recovery: &RecoveryProposal) {
+    let key = RecoveryKey::Recovery(recovery.id);
+    env.storage().persistent().set(&key, recovery);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_recovery(env: &Env, id: u64) -> Result<RecoveryProposal, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&RecoveryKey::Recovery(id))
+        .ok_or(VaultError::ProposalNotFound)
+}
+
+// ============================================================================
+// Succession
+// ============================================================================
+
+pub fn get_last_activity(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&SignerKey::LastSignerActivity)
+        .unwrap_or(0)
+}
+
+pub fn set_last_activity(env: &Env, ledger: u64) {
+    env.storage()
+        .instance()
+        .set(&SignerKey::LastSignerActivity, &ledger);
+}
+
+pub fn get_signer_activity(env: &Env, addr: &Address) -> SignerActivity {
+    env.storage()
+        .persistent()
+        .get(&SignerKey::SignerActivity(addr.clone()))
+        .unwrap_or_default()
+}
+
+pub fn set_signer_activity(env: &Env, addr: &Address, activity: &SignerActivity) {
+    let key = SignerKey::SignerActivity(addr.clone());
+    env.storage().persistent().set(&key, activity);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn remove_signer_activity(env: &Env, addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&SignerKey::SignerActivity(addr.clone()));
+}
+
+pub fn get_active_signer_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&SignerKey::ActiveSignerWindow)
+        .unwrap_or(0)
+}
+
+pub fn set_active_signer_window(env: &Env, window: u64) {
+    env.storage()
+        .instance()
+        .set(&SignerKey::ActiveSignerWindow, &window);
+}
+
+pub fn get_max_signers(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&SignerKey::MaxSigners)
+        .unwrap_or(DEFAULT_MAX_SIGNERS)
+}
+
+pub fn set_max_signers(env: &Env, max: u32) {
+    env.storage().instance().set(&SignerKey::MaxSigners, &max);
+}
+
+pub fn get_succession_plan(env: &Env) -> Option<SuccessionPlan> {
+    env.storage().instance().get(&RecoveryKey::SuccessionPlan)
+}
+
+pub fn set_succession_plan(env: &Env, plan: &SuccessionPlan) {
+    env.storage()
+        .instance()
+        .set(&RecoveryKey::SuccessionPlan, plan);
+}
+
+pub fn remove_succession_plan(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&RecoveryKey::SuccessionPlan);
+}
+
 // ============================================================================
 // Proposals
 // ============================================================================
@@ -174,22 +641,251 @@ pub fn add_weekly_spent(env: &Env, week: u64, amount: i128) {
 }
 
 // ============================================================================
-// Recurring Payments
+// Rolling Window Spending
 // ============================================================================
 
-pub fn get_next_recurring_id(env: &Env) -> u64 {
+/// Number of hourly buckets kept for vault-wide spending (one week)
+const SPEND_RING_HOURS: u64 = 168;
+/// Number of hourly buckets kept for per-address spending (one day)
+const ADDRESS_RING_HOURS: u64 = 24;
+
+/// Load a spending ring of `size` hourly buckets, clearing buckets for hours
+/// elapsed since the last write
+fn load_spend_ring(env: &Env, key: &DataKey, size: u64) -> SpendRing {
+    let now_hour = env.ledger().timestamp() / 3600;
+    let mut ring = env.storage().temporary().get(key).unwrap_or_else(|| {
+        let mut buckets = soroban_sdk::Vec::new(env);
+        for _ in 0..size {
+            buckets.push_back(0i128);
+        }
+        SpendRing {
+            buckets,
+            last_hour: now_hour,
+        }
+    });
+
+    if now_hour > ring.last_hour {
+        let gap = (now_hour - ring.last_hour).min(size);
+        for h in 1..=gap {
+            let idx = ((ring.last_hour + h) % size) as u32;
+            ring.buckets.set(idx, 0);
+        }
+        ring.last_hour = now_hour;
+    }
+    ring
+}
+
+/// Sum a ring's last `hours` buckets (including the current hour)
+fn sum_spend_ring(env: &Env, key: &DataKey, size: u64, hours: u64) -> i128 {
+    let ring = load_spend_ring(env, key, size);
+    let mut total = 0;
+    for h in 0..hours.min(size) {
+        let idx = ((ring.last_hour + size - h) % size) as u32;
+        total += ring.buckets.get(idx).unwrap_or(0);
+    }
+    total
+}
+
+/// Add to a ring's current-hour bucket, keeping it alive for `size` hours plus a day
+fn add_to_spend_ring(env: &Env, key: &DataKey, size: u64, amount: i128) {
+    let mut ring = load_spend_ring(env, key, size);
+    let idx = (ring.last_hour % size) as u32;
+    let current = ring.buckets.get(idx).unwrap_or(0);
+    ring.buckets.set(idx, current + amount);
+    env.storage().temporary().set(key, &ring);
+    // Older buckets fall outside the window anyway once the entry expires
+    let ttl = DAY_IN_LEDGERS * (size / 24 + 1) as u32;
+    env.storage().temporary().extend_ttl(key, ttl, ttl);
+}
+
+/// Get vault-wide spending over the last `hours` hours (including the current one)
+pub fn get_rolling_spent(env: &Env, hours: u64) -> i128 {
+    sum_spend_ring(env, &DataKey::SpendRing, SPEND_RING_HOURS, hours)
+}
+
+fn add_rolling_spent(env: &Env, amount: i128) {
+    add_to_spend_ring(env, &DataKey::SpendRing, SPEND_RING_HOURS, amount);
+}
+
+// ============================================================================
+// Timelock Tiers
+// ============================================================================
+
+pub fn get_timelock_tiers(env: &Env) -> soroban_sdk::Vec<TimelockTier> {
     env.storage()
         .instance()
-        .get(&DataKey::NextRecurringId)
+        .get(&DataKey::TimelockTiers)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+pub fn set_timelock_tiers(env: &Env, tiers: &soroban_sdk::Vec<TimelockTier>) {
+    env.storage().instance().set(&DataKey::TimelockTiers, tiers);
+}
+
+pub fn get_veto_threshold(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::VetoThreshold)
         .unwrap_or(1)
 }
 
-pub fn increment_recurring_id(env: &Env) -> u64 {
-    let id = get_next_recurring_id(env);
+pub fn set_veto_threshold(env: &Env, threshold: u32) {
     env.storage()
         .instance()
-        .set(&DataKey::NextRecurringId, &(id + 1));
-    id
+        .set(&DataKey::VetoThreshold, &threshold);
+}
+
+// ============================================================================
+// Per-Address Caps
+// ============================================================================
+
+pub fn get_address_caps(env: &Env) -> AddressCaps {
+    env.storage()
+        .instance()
+        .get(&DataKey::AddressCaps)
+        .unwrap_or(AddressCaps {
+            recipient_cap: 0,
+            proposer_cap: 0,
+        })
+}
+
+pub fn set_address_caps(env: &Env, caps: &AddressCaps) {
+    env.storage().instance().set(&DataKey::AddressCaps, caps);
+}
+
+/// Get the amount paid to a recipient over the last 24 hours
+pub fn get_recipient_spent(env: &Env, recipient: &Address) -> i128 {
+    let key = DataKey::RecipientSpent(recipient.clone());
+    sum_spend_ring(env, &key, ADDRESS_RING_HOURS, ADDRESS_RING_HOURS)
+}
+
+/// Get the amount moved by a proposer over the last 24 hours
+pub fn get_proposer_spent(env: &Env, proposer: &Address) -> i128 {
+    let key = DataKey::ProposerSpent(proposer.clone());
+    sum_spend_ring(env, &key, ADDRESS_RING_HOURS, ADDRESS_RING_HOURS)
+}
+
+/// Record spending against a recipient's and a proposer's rolling caps
+pub fn add_address_spent(env: &Env, recipient: &Address, proposer: &Address, amount: i128) {
+    let key = DataKey::RecipientSpent(recipient.clone());
+    add_to_spend_ring(env, &key, ADDRESS_RING_HOURS, amount);
+    let key = DataKey::ProposerSpent(proposer.clone());
+    add_to_spend_ring(env, &key, ADDRESS_RING_HOURS, amount);
+}
+
+/// Record spending in both calendar buckets and the rolling ring buffer
+pub fn add_spent(env: &Env, amount: i128) {
+    add_daily_spent(env, get_day_number(env), amount);
+    add_weekly_spent(env, get_week_number(env), amount);
+    add_rolling_spent(env, amount);
+}
+
+// ============================================================================
+// Category Budgets
+// ============================================================================
+
+/// Get current calendar month number (year * 12 + month index, UTC)
+pub fn get_month_number(env: &Env) -> u64 {
+    // Civil-from-days conversion (proleptic Gregorian calendar)
+    let z = env.ledger().timestamp() / 86400 + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 2 } else { mp - 10 };
+    let year = yoe + era * 400 + u64::from(month < 2);
+    year * 12 + month
+}
+
+/// Get current calendar quarter number (year * 4 + quarter index, UTC)
+pub fn get_quarter_number(env: &Env) -> u64 {
+    get_month_number(env) / 3
+}
+
+pub fn get_budget(env: &Env, category: &Symbol) -> Option<BudgetCategory> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Budget(category.clone()))
+}
+
+pub fn set_budget(env: &Env, budget: &BudgetCategory) {
+    let key = DataKey::Budget(budget.category.clone());
+    env.storage().persistent().set(&key, budget);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+
+    let mut categories = get_budget_categories(env);
+    if !categories.contains(&budget.category) {
+        categories.push_back(budget.category.clone());
+        let key = DataKey::BudgetCategories;
+        env.storage().persistent().set(&key, &categories);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+    }
+}
+
+pub fn get_budget_categories(env: &Env) -> soroban_sdk::Vec<Symbol> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BudgetCategories)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+pub fn get_monthly_spent(env: &Env, category: &Symbol, month: u64) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::MonthlySpent(category.clone(), month))
+        .unwrap_or(0)
+}
+
+pub fn get_quarterly_spent(env: &Env, category: &Symbol, quarter: u64) -> i128 {
+    env.storage()
+        .temporary()
+        .get(&DataKey::QuarterlySpent(category.clone(), quarter))
+        .unwrap_or(0)
+}
+
+/// Record category spending for the current month and quarter
+pub fn add_category_spent(env: &Env, category: &Symbol, amount: i128) {
+    let month = get_month_number(env);
+    let key = DataKey::MonthlySpent(category.clone(), month);
+    let current = get_monthly_spent(env, category, month);
+    env.storage().temporary().set(&key, &(current + amount));
+    // TTL: 32 days
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 32, DAY_IN_LEDGERS * 32);
+
+    let quarter = get_quarter_number(env);
+    let key = DataKey::QuarterlySpent(category.clone(), quarter);
+    let current = get_quarterly_spent(env, category, quarter);
+    env.storage().temporary().set(&key, &(current + amount));
+    // TTL: 93 days
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, DAY_IN_LEDGERS * 93, DAY_IN_LEDGERS * 93);
+}
+
+// ============================================================================
+// Recurring Payments
+// ============================================================================
+
+pub fn get_next_recurring_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextRecurringId)
+        .unwrap_or(1)
+}
+
+pub fn increment_recurring_id(env: &Env) -> u64 {
+    let id = get_next_recurring_id(env);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextRecurringId, &(id + 1));
+    id
 }
 
 pub fn set_recurring_payment(env: &Env, payment: &crate::types::RecurringPayment) {
@@ -210,6 +906,397 @@ pub fn get_recurring_payment(
         .ok_or(VaultError::ProposalNotFound) // Reuse code or add new
 }
 
+pub fn get_limit_reservations(env: &Env) -> soroban_sdk::Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LimitReservations)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn set_limit_reservations(env: &Env, ids: &soroban_sdk::Vec<u64>) {
+    let key = DataKey::LimitReservations;
+    env.storage().persistent().set(&key, ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn add_limit_reservation(env: &Env, payment_id: u64) {
+    let mut ids = get_limit_reservations(env);
+    if !ids.contains(payment_id) {
+        ids.push_back(payment_id);
+        set_limit_reservations(env, &ids);
+    }
+}
+
+pub fn remove_limit_reservation(env: &Env, payment_id: u64) {
+    let mut ids = get_limit_reservations(env);
+    if let Some(idx) = ids.iter().position(|id| id == payment_id) {
+        ids.remove(idx as u32);
+        set_limit_reservations(env, &ids);
+    }
+}
+
+// ============================================================================
+// Vesting
+// ============================================================================
+
+pub fn get_next_vesting_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ScheduleKey::NextVestingId)
+        .unwrap_or(1)
+}
+
+pub fn increment_vesting_id(env: &Env) -> u64 {
+    let id = get_next_vesting_id(env);
+    env.storage()
+        .instance()
+        .set(&ScheduleKey::NextVestingId, &(id + 1));
+    id
+}
+
+pub fn set_vesting(env: &Env, grant: &VestingGrant) {
+    let key = ScheduleKey::Vesting(grant.id);
+    env.storage().persistent().set(&key, grant);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_vesting(env: &Env, id: u64) -> Result<VestingGrant, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&ScheduleKey::Vesting(id))
+        .ok_or(VaultError::VestingNotFound)
+}
+
+// ============================================================================
+// Streams
+// ============================================================================
+
+pub fn get_next_stream_id(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&ScheduleKey::NextStreamId)
+        .unwrap_or(1)
+}
+
+pub fn increment_stream_id(env: &Env) -> u64 {
+    let id = get_next_stream_id(env);
+    env.storage()
+        .instance()
+        .set(&ScheduleKey::NextStreamId, &(id + 1));
+    id
+}
+
+pub fn set_stream(env: &Env, stream: &Stream) {
+    let key = ScheduleKey::Stream(stream.id);
+    env.storage().persistent().set(&key, stream);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_stream(env: &Env, id: u64) -> Result<Stream, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&ScheduleKey::Stream(id))
+        .ok_or(VaultError::StreamNotFound)
+}
+
+pub fn get_liability(env: &Env, token_addr: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ScheduleKey::Liability(token_addr.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjust the running liability of a token by `delta`.
+pub fn add_liability(env: &Env, token_addr: &Address, delta: i128) {
+    if delta == 0 {
+        return;
+    }
+    let key = ScheduleKey::Liability(token_addr.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &get_liability(env, token_addr).saturating_add(delta));
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, INSTANCE_TTL_THRESHOLD, INSTANCE_TTL);
+}
+
+// ============================================================================
+// Address Book
+// ============================================================================
+
+pub fn get_address_book_entry(env: &Env, addr: &Address) -> Option<AddressBookEntry> {
+    env.storage()
+        .persistent()
+        .get(&AddressKey::AddressBook(addr.clone()))
+}
+
+pub fn get_address_book_index(env: &Env) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AddressKey::AddressBookIndex)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn set_address_book_index(env: &Env, index: &soroban_sdk::Vec<Address>) {
+    let key = AddressKey::AddressBookIndex;
+    env.storage().persistent().set(&key, index);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn set_address_book_entry(env: &Env, entry: &AddressBookEntry) {
+    let key = AddressKey::AddressBook(entry.address.clone());
+    env.storage().persistent().set(&key, entry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+
+    let mut index = get_address_book_index(env);
+    if !index.contains(&entry.address) {
+        index.push_back(entry.address.clone());
+        set_address_book_index(env, &index);
+    }
+}
+
+pub fn remove_address_book_entry(env: &Env, addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&AddressKey::AddressBook(addr.clone()));
+
+    let mut index = get_address_book_index(env);
+    if let Some(idx) = index.iter().position(|a| a == *addr) {
+        index.remove(idx as u32);
+        set_address_book_index(env, &index);
+    }
+}
+
+pub fn increment_address_book_proposal_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&AddressKey::NextAddressBookProposalId)
+        .unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&AddressKey::NextAddressBookProposalId, &(id + 1));
+    id
+}
+
+pub fn set_address_book_proposal(env: &Env, proposal: &AddressBookProposal) {
+    let key = AddressKey::AddressBookProposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PROPOSAL_TTL / 2, PROPOSAL_TTL);
+}
+
+pub fn get_address_book_proposal(env: &Env, id: u64) -> Result<AddressBookProposal, VaultError> {
+    env.storage()
+        .persistent()
+        .get(&AddressKey::AddressBookProposal(id))
+        .ok_or(VaultError::AddressBookProposalNotFound)
+}
+
+// ============================================================================
+// Denylist
+// ============================================================================
+
+pub fn is_denylisted(env: &Env, addr: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AddressKey::Denylisted(addr.clone()))
+}
+
+pub fn get_denylist(env: &Env) -> soroban_sdk::Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&AddressKey::DenylistIndex)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn set_denylist(env: &Env, list: &soroban_sdk::Vec<Address>) {
+    let key = AddressKey::DenylistIndex;
+    env.storage().persistent().set(&key, list);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn add_to_denylist(env: &Env, addr: &Address) {
+    let key = AddressKey::Denylisted(addr.clone());
+    env.storage().persistent().set(&key, &true);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+
+    let mut list = get_denylist(env);
+    if !list.contains(addr) {
+        list.push_back(addr.clone());
+        set_denylist(env, &list);
+    }
+}
+
+pub fn remove_from_denylist(env: &Env, addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&AddressKey::Denylisted(addr.clone()));
+
+    let mut list = get_denylist(env);
+    if let Some(idx) = list.iter().position(|a| a == *addr) {
+        list.remove(idx as u32);
+        set_denylist(env, &list);
+    }
+}
+
+// ============================================================================
+// Token Registry
+// ============================================================================
+
+pub fn get_token_info(env: &Env, token_addr: &Address) -> Option<TokenInfo> {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::Token(token_addr.clone()))
+}
+
+pub fn set_token_info(env: &Env, info: &TokenInfo) {
+    let key = TokenKey::Token(info.address.clone());
+    env.storage().persistent().set(&key, info);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+
+    let mut registry = get_token_registry(env);
+    if !registry.tokens.contains(&info.address) {
+        registry.tokens.push_back(info.address.clone());
+        set_token_registry(env, &registry);
+    }
+}
+
+pub fn remove_token_info(env: &Env, token_addr: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&TokenKey::Token(token_addr.clone()));
+
+    let mut registry = get_token_registry(env);
+    if let Some(idx) = registry.tokens.first_index_of(token_addr) {
+        registry.tokens.remove(idx);
+        set_token_registry(env, &registry);
+    }
+}
+
+pub fn get_token_registry(env: &Env) -> TokenRegistry {
+    env.storage()
+        .persistent()
+        .get(&TokenKey::TokenRegistry)
+        .unwrap_or(TokenRegistry {
+            tokens: soroban_sdk::Vec::new(env),
+            enforced: false,
+        })
+}
+
+pub fn set_token_registry(env: &Env, registry: &TokenRegistry) {
+    let key = TokenKey::TokenRegistry;
+    env.storage().persistent().set(&key, registry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+// ============================================================================
+// Recipient Indexes
+// ============================================================================
+
+fn get_id_index(env: &Env, key: &DataKey) -> soroban_sdk::Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(key)
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+fn push_id_index(env: &Env, key: &DataKey, id: u64) {
+    let mut ids = get_id_index(env, key);
+    ids.push_back(id);
+    env.storage().persistent().set(key, &ids);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL);
+}
+
+pub fn get_recipient_recurring(env: &Env, recipient: &Address) -> soroban_sdk::Vec<u64> {
+    get_id_index(env, &DataKey::RecipientRecurring(recipient.clone()))
+}
+
+pub fn add_recipient_recurring(env: &Env, recipient: &Address, payment_id: u64) {
+    push_id_index(
+        env,
+        &DataKey::RecipientRecurring(recipient.clone()),
+        payment_id,
+    );
+}
+
+pub fn get_recipient_proposals(env: &Env, recipient: &Address) -> soroban_sdk::Vec<u64> {
+    get_id_index(env, &DataKey::RecipientProposals(recipient.clone()))
+}
+
+pub fn add_recipient_proposal(env: &Env, recipient: &Address, proposal_id: u64) {
+    push_id_index(
+        env,
+        &DataKey::RecipientProposals(recipient.clone()),
+        proposal_id,
+    );
+}
+
+pub fn get_recipient_streams(env: &Env, recipient: &Address) -> soroban_sdk::Vec<u64> {
+    get_id_index(env, &DataKey::RecipientStreams(recipient.clone()))
+}
+
+pub fn add_recipient_stream(env: &Env, recipient: &Address, stream_id: u64) {
+    push_id_index(
+        env,
+        &DataKey::RecipientStreams(recipient.clone()),
+        stream_id,
+    );
+}
+
+fn remove_id_index(env: &Env, key: &DataKey, id: u64) {
+    let mut ids = get_id_index(env, key);
+    if let Some(idx) = ids.first_index_of(id) {
+        ids.remove(idx);
+        env.storage().persistent().set(key, &ids);
+    }
+}
+
+pub fn remove_recipient_recurring(env: &Env, recipient: &Address, payment_id: u64) {
+    remove_id_index(
+        env,
+        &DataKey::RecipientRecurring(recipient.clone()),
+        payment_id,
+    );
+}
+
+pub fn remove_recipient_proposal(env: &Env, recipient: &Address, proposal_id: u64) {
+    remove_id_index(
+        env,
+        &DataKey::RecipientProposals(recipient.clone()),
+        proposal_id,
+    );
+}
+
+pub fn remove_recipient_stream(env: &Env, recipient: &Address, stream_id: u64) {
+    remove_id_index(
+        env,
+        &DataKey::RecipientStreams(recipient.clone()),
+        stream_id,
+    );
+}
+
 // ============================================================================
 // Priority Queue Management
 // ============================================================================
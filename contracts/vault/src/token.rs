@@ -2,7 +2,7 @@
 //!
 //! Client wrapper for Stellar Asset Contracts (SAC) and custom tokens.
 
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, Address, Env, String};
 
 /// Transfer tokens from the vault to a recipient
 pub fn transfer(env: &Env, token_addr: &Address, to: &Address, amount: i128) {
@@ -11,6 +11,16 @@ pub fn transfer(env: &Env, token_addr: &Address, to: &Address, amount: i128) {
     client.transfer(&vault_address, to, &amount);
 }
 
+/// Get a token's decimals
+pub fn decimals(env: &Env, token_addr: &Address) -> u32 {
+    token::Client::new(env, token_addr).decimals()
+}
+
+/// Get a token's symbol
+pub fn symbol(env: &Env, token_addr: &Address) -> String {
+    token::Client::new(env, token_addr).symbol()
+}
+
 /// Get the vault's balance of a token
 pub fn balance(env: &Env, token_addr: &Address) -> i128 {
     let client = token::Client::new(env, token_addr);
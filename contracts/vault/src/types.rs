@@ -2,7 +2,7 @@
 //!
 //! Core data structures for the multisig treasury contract.
 
-use soroban_sdk::{contracttype, Address, Symbol, Vec};
+use soroban_sdk::{contracttype, Address, String, Symbol, Vec};
 
 /// Initialization configuration - groups all config params to reduce function arguments
 #[contracttype]
@@ -20,8 +20,14 @@ pub struct InitConfig {
     pub weekly_limit: i128,
     /// Amount threshold above which a timelock applies
     pub timelock_threshold: i128,
-    /// Delay in ledgers for timelocked proposals
+    /// Delay for timelocked proposals, in ledgers or seconds per `timelock_clock`
     pub timelock_delay: u64,
+    /// How daily/weekly spending limits are measured
+    pub limit_window_mode: LimitWindowMode,
+    /// Only allow payments to addresses in the address book
+    pub allowlist_only: bool,
+    /// Whether timelocks and proposal expiry use ledger sequence or timestamps
+    pub timelock_clock: TimelockClock,
     /// Threshold strategy configuration
     pub threshold_strategy: ThresholdStrategy,
 }
@@ -42,12 +48,34 @@ pub struct Config {
     pub weekly_limit: i128,
     /// Amount threshold above which a timelock applies
     pub timelock_threshold: i128,
-    /// Delay in ledgers for timelocked proposals
+    /// Delay for timelocked proposals, in ledgers or seconds per `timelock_clock`
     pub timelock_delay: u64,
+    /// How daily/weekly spending limits are measured
+    pub limit_window_mode: LimitWindowMode,
+    /// Only allow payments to addresses in the address book
+    pub allowlist_only: bool,
+    /// Whether timelocks and proposal expiry use ledger sequence or timestamps
+    pub timelock_clock: TimelockClock,
     /// Threshold strategy configuration
     pub threshold_strategy: ThresholdStrategy,
 }
 
+/// Partial configuration update; `None` fields keep their current value
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ConfigUpdate {
+    /// Maximum amount per proposal (in stroops)
+    pub spending_limit: Option<i128>,
+    /// Maximum aggregate daily spending (in stroops)
+    pub daily_limit: Option<i128>,
+    /// Maximum aggregate weekly spending (in stroops)
+    pub weekly_limit: Option<i128>,
+    /// Amount threshold above which a timelock applies
+    pub timelock_threshold: Option<i128>,
+    /// Delay for timelocked proposals, in ledgers or seconds per `timelock_clock`
+    pub timelock_delay: Option<u64>,
+}
+
 /// Threshold strategy for dynamic approval requirements
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -62,6 +90,82 @@ pub enum ThresholdStrategy {
     TimeBased(TimeBasedThreshold),
 }
 
+/// How daily and weekly spending limits are measured
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum LimitWindowMode {
+    /// Fixed calendar buckets (UTC day / epoch week), reset at the boundary
+    Calendar = 0,
+    /// Rolling 24h / 7d windows over hourly buckets
+    Rolling = 1,
+}
+
+/// How timelock delays and proposal expiry are measured
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum TimelockClock {
+    /// Ledger sequence numbers; delays are in ledgers
+    Ledger = 0,
+    /// Ledger close timestamps; delays are in seconds
+    Timestamp = 1,
+}
+
+/// Spending limit window
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SpendWindow {
+    /// Counted against `daily_limit`
+    Daily = 0,
+    /// Counted against `weekly_limit`
+    Weekly = 1,
+}
+
+/// Hourly spending ring buffer backing rolling limit windows
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SpendRing {
+    /// Amount spent per hour, indexed by `hour % buckets.len()`
+    pub buckets: Vec<i128>,
+    /// Most recent hour (timestamp / 3600) recorded in the buffer
+    pub last_hour: u64,
+}
+
+/// Per-address rolling 24h spending caps
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AddressCaps {
+    /// Maximum amount any single recipient can receive per 24h (0 = uncapped)
+    pub recipient_cap: i128,
+    /// Maximum amount any single proposer can move per 24h (0 = uncapped)
+    pub proposer_cap: i128,
+}
+
+/// Budget caps for a spending category
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BudgetCategory {
+    /// Category tag proposals are labelled with
+    pub category: Symbol,
+    /// Maximum spending per calendar month (0 = uncapped)
+    pub monthly_cap: i128,
+    /// Maximum spending per calendar quarter (0 = uncapped)
+    pub quarterly_cap: i128,
+}
+
+/// Budget accounting period
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum BudgetPeriod {
+    /// Calendar month (UTC)
+    Monthly = 0,
+    /// Calendar quarter (UTC)
+    Quarterly = 1,
+}
+
 /// Amount-based threshold tier
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -72,6 +176,16 @@ pub struct AmountTier {
     pub approvals: u32,
 }
 
+/// Amount-based timelock tier
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TimelockTier {
+    /// Minimum amount (inclusive) for this tier
+    pub min_amount: i128,
+    /// Delay applied after final approval, in the vault's `timelock_clock` unit
+    pub delay: u64,
+}
+
 /// Time-based threshold configuration
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -95,8 +209,54 @@ pub enum Role {
     Treasurer = 1,
     /// Full operational control: manages roles, signers, and configuration.
     Admin = 2,
+    /// Security role: manages the recipient denylist but cannot move funds.
+    Guardian = 3,
+}
+
+impl Role {
+    /// Preset permission mask for this role.
+    pub fn permissions(&self) -> u32 {
+        match self {
+            Role::Member => 0,
+            Role::Treasurer => {
+                Permission::Propose as u32 | Permission::Approve as u32 | Permission::Execute as u32
+            }
+            Role::Admin => ALL_PERMISSIONS,
+            Role::Guardian => Permission::ManageDenylist as u32,
+        }
+    }
 }
 
+/// Fine-grained permission bits; an address holds a `u32` mask of these.
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Permission {
+    /// Create transfer, recurring, vesting, stream and address book proposals
+    Propose = 1,
+    /// Approve or abstain on proposals
+    Approve = 2,
+    /// Execute approved transfer proposals
+    Execute = 4,
+    /// Add and remove signers, change approval and veto thresholds
+    ManageSigners = 8,
+    /// Assign roles and permissions
+    ManageRoles = 16,
+    /// Change spending limits, timelocks, budgets and caps
+    ManageLimits = 32,
+    /// Attach documents to any proposal
+    Attach = 64,
+    /// Manage the recipient denylist
+    ManageDenylist = 128,
+    /// Manage the supported token registry
+    ManageTokens = 256,
+    /// Reject, reprioritise, revoke or cancel proposals created by others
+    ManageProposals = 512,
+}
+
+/// Mask with every defined permission bit set
+pub const ALL_PERMISSIONS: u32 = 1023;
+
 /// Priority levels for proposals.
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -123,6 +283,8 @@ pub enum ProposalStatus {
     Rejected = 3,
     /// Reached expiration ledger without hitting the approval threshold.
     Expired = 4,
+    /// Vetoed by signers while approved but still timelocked.
+    Cancelled = 5,
 }
 
 /// Transfer proposal
@@ -151,12 +313,26 @@ pub struct Proposal {
     pub priority: Priority,
     /// IPFS hashes for attachments (invoices, receipts, documents)
     pub attachments: Vec<soroban_sdk::String>,
+    /// Budget category (e.g. payroll, grants, infra)
+    pub category: Symbol,
+    /// Set when the recipient was denylisted while the proposal was open
+    pub flagged: bool,
+    /// Signers who vetoed the proposal during its timelock
+    pub vetoes: Vec<Address>,
+    /// Reason given by the veto that cancelled the proposal
+    pub cancel_reason: Option<Symbol>,
     /// Ledger sequence when created
     pub created_at: u64,
     /// Ledger sequence when proposal expires
     pub expires_at: u64,
     /// Earliest ledger sequence when proposal can be executed (0 if no timelock)
     pub unlock_ledger: u64,
+    /// Ledger timestamp when created
+    pub created_time: u64,
+    /// Ledger timestamp when proposal expires
+    pub expires_time: u64,
+    /// Earliest timestamp when proposal can be executed (0 if no timelock)
+    pub unlock_time: u64,
 }
 
 /// Recurring payment schedule
@@ -177,4 +353,302 @@ pub struct RecurringPayment {
     pub payment_count: u32,
     /// Configured status (Active/Stopped)
     pub is_active: bool,
+    /// Outstanding amount from missed payments
+    pub arrears: i128,
+    /// Total number of missed payments
+    pub failed_count: u32,
+    /// `VaultError` code of the most recent missed payment (0 if none)
+    pub last_failure: u32,
+    /// Whether the next amount is reserved against daily/weekly limits
+    pub reserve_limits: bool,
+}
+
+/// Per-schedule outcome of a batch recurring payment execution
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecurringExecResult {
+    /// Recurring payment ID
+    pub payment_id: u64,
+    /// Whether the payment was executed
+    pub executed: bool,
+    /// `VaultError` code when skipped (0 if executed)
+    pub error_code: u32,
+}
+
+/// Lifecycle states of a vesting grant.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum VestingStatus {
+    /// Awaiting signer approvals.
+    Pending = 0,
+    /// Approved and releasing tokens.
+    Active = 1,
+    /// Revoked by an admin; only the amount vested at revocation remains claimable.
+    Revoked = 2,
+    /// Fully vested and claimed.
+    Completed = 3,
+}
+
+/// Vesting timing parameters, in ledgers
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VestingSchedule {
+    /// Ledger sequence when vesting starts
+    pub start_ledger: u64,
+    /// Ledgers after start before anything vests
+    pub cliff: u64,
+    /// Ledgers after start until the grant is fully vested
+    pub duration: u64,
+}
+
+/// Cliff-then-linear vesting grant
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VestingGrant {
+    pub id: u64,
+    pub proposer: Address,
+    pub beneficiary: Address,
+    pub token: Address,
+    /// Total amount granted (reduced to the vested amount on revocation)
+    pub total: i128,
+    /// Amount already claimed by the beneficiary
+    pub claimed: i128,
+    /// Release schedule
+    pub schedule: VestingSchedule,
+    /// Whether an admin may revoke the unvested remainder
+    pub revocable: bool,
+    /// Addresses that have approved the grant
+    pub approvals: Vec<Address>,
+    /// Current status
+    pub status: VestingStatus,
+}
+
+/// Lifecycle states of a payment stream.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum StreamStatus {
+    /// Awaiting signer approvals.
+    Pending = 0,
+    /// Approved and accruing every ledger.
+    Active = 1,
+    /// Cancelled; accrual stopped at `end_ledger`.
+    Cancelled = 2,
+    /// Fully accrued and withdrawn.
+    Completed = 3,
+}
+
+/// Continuous per-ledger payment stream
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Stream {
+    pub id: u64,
+    pub proposer: Address,
+    pub recipient: Address,
+    pub token: Address,
+    /// Amount accrued per ledger
+    pub rate_per_ledger: i128,
+    /// Streaming duration in ledgers
+    pub duration: u64,
+    /// Ledger sequence when accrual started (0 while pending)
+    pub start_ledger: u64,
+    /// Ledger sequence when accrual stops (0 while pending)
+    pub end_ledger: u64,
+    /// Amount already withdrawn by the recipient
+    pub withdrawn: i128,
+    /// Addresses that have approved the stream
+    pub approvals: Vec<Address>,
+    /// Current status
+    pub status: StreamStatus,
+}
+
+/// Supported token in the vault's token registry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenInfo {
+    pub address: Address,
+    /// Token symbol as reported by the token contract
+    pub symbol: String,
+    /// Token decimals as reported by the token contract
+    pub decimals: u32,
+    /// Disabled tokens are rejected on every transfer path
+    pub enabled: bool,
+    /// Ledger sequence when the token was registered
+    pub added_at: u64,
+}
+
+/// Registered token addresses and whether transfers are restricted to them
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TokenRegistry {
+    pub tokens: Vec<Address>,
+    /// Reject tokens outside the registry on every transfer path
+    pub enforced: bool,
+}
+
+/// Known payee in the vault's address book
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AddressBookEntry {
+    pub address: Address,
+    /// Human-readable label (e.g. vendor name)
+    pub label: Symbol,
+    /// Signer who proposed the entry
+    pub added_by: Address,
+    /// Ledger sequence when the entry took effect
+    pub added_at: u64,
+}
+
+/// Change requested to the address book.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AddressBookAction {
+    /// Add (or relabel) an entry.
+    Add = 0,
+    /// Remove an entry.
+    Remove = 1,
+    /// Turn allowlist-only mode on.
+    EnableAllowlist = 2,
+    /// Turn allowlist-only mode off.
+    DisableAllowlist = 3,
+}
+
+/// Audit record of a role change
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RoleChange {
+    /// Role held before the change
+    pub previous: Role,
+    /// Role assigned by the change
+    pub role: Role,
+    /// Ledger after which the role lapses to Member (None if permanent)
+    pub expires_at: Option<u64>,
+    /// Address that made the change
+    pub changed_by: Address,
+    /// Ledger sequence of the change
+    pub ledger: u64,
+    /// Ledger timestamp of the change
+    pub timestamp: u64,
+}
+
+/// Social recovery settings for replacing the signer set
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecoveryConfig {
+    /// Addresses allowed to propose and approve a recovery (unrelated to `Role::Guardian`)
+    pub recovery_guardians: Vec<Address>,
+    /// Recovery guardian approvals required to start the recovery delay
+    pub threshold: u32,
+    /// Ledgers between reaching the threshold and the recovery taking effect
+    pub delay: u64,
+}
+
+/// Recovery lifecycle
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum RecoveryStatus {
+    /// Collecting guardian approvals or waiting out the delay
+    Pending = 0,
+    /// New signer set installed
+    Executed = 1,
+    /// Cancelled by an existing signer
+    Cancelled = 2,
+}
+
+/// Guardian proposal to replace the signer set
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RecoveryProposal {
+    pub id: u64,
+    pub proposer: Address,
+    /// Replacement signer set
+    pub new_signers: Vec<Address>,
+    /// Replacement approval threshold
+    pub new_threshold: u32,
+    /// Member of `new_signers` who becomes Admin
+    pub new_admin: Address,
+    /// Guardians that have approved
+    pub approvals: Vec<Address>,
+    /// Earliest ledger the recovery can execute (0 until the threshold is met)
+    pub executable_at: u64,
+    pub status: RecoveryStatus,
+}
+
+/// Per-signer liveness record
+#[contracttype]
+#[derive(Clone, Debug, Default)]
+pub struct SignerActivity {
+    /// Ledger of the signer's last approval or abstention (0 if never)
+    pub last_vote: u64,
+    /// Ledger of the signer's last heartbeat (0 if never)
+    pub last_heartbeat: u64,
+}
+
+/// Inactivity-triggered succession plan
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SuccessionPlan {
+    /// Replacement signer set (empty to only sweep funds); the first becomes Admin
+    pub successors: Vec<Address>,
+    /// Approval threshold for the successor set
+    pub threshold: u32,
+    /// Backup address that receives swept funds (None to only hand over control)
+    pub beneficiary: Option<Address>,
+    /// Ledgers without signer activity before succession can be triggered
+    pub inactivity_period: u64,
+}
+
+/// Pending grant of the Admin role awaiting signer approvals
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdminGrant {
+    pub id: u64,
+    pub proposer: Address,
+    /// Address to be promoted to Admin
+    pub target: Address,
+    /// Addresses that have approved the grant
+    pub approvals: Vec<Address>,
+    /// Whether the grant has been applied
+    pub executed: bool,
+}
+
+/// Recovery or succession settings change
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum SafeguardChange {
+    /// Replace the social recovery settings
+    RecoveryConfig(RecoveryConfig),
+    /// Replace the inactivity-triggered succession plan
+    SuccessionPlan(SuccessionPlan),
+}
+
+/// Pending safeguard change awaiting signer approvals
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SafeguardProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub change: SafeguardChange,
+    /// Addresses that have approved the change
+    pub approvals: Vec<Address>,
+    /// Whether the change has been applied
+    pub executed: bool,
+}
+
+/// Pending address book change awaiting signer approvals
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AddressBookProposal {
+    pub id: u64,
+    pub proposer: Address,
+    pub action: AddressBookAction,
+    pub address: Address,
+    pub label: Symbol,
+    /// Addresses that have approved the change
+    pub approvals: Vec<Address>,
+    /// Whether the change has been applied
+    pub executed: bool,
 }